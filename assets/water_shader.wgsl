@@ -1,6 +1,10 @@
-#import bevy_pbr::{mesh_view_bindings::globals, forward_io::VertexOutput}
+#import bevy_sprite::{mesh2d_view_bindings::globals, mesh2d_vertex_output::VertexOutput}
 
 const OCTAVE = 6;
+// World units per pixel of the pattern, matches the scaled pixel art.
+const PIXEL_SIZE = 4.0;
+// World units covered by one unit of UV, keeps the pattern seamless across chunks.
+const UV_WORLD_SIZE = 2000.0;
 
 struct Params {
     scale: f32,
@@ -9,6 +13,8 @@ struct Params {
     foam_thickness: f32,
     time_scale: f32,
     water_depth: f32,
+    // Keeps the phase continuous when `time_scale` changes.
+    time_offset: f32,
 };
 
 struct Color {
@@ -52,9 +58,8 @@ fn fbm(uv: vec2<f32>) -> f32 {
 
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-    let pixelation: f32 = 25000.0;
-    let UV = floor(in.uv * pixelation) / pixelation;
-    var time: f32 = params.time_scale;
+    let UV = floor(in.world_position.xy / PIXEL_SIZE) * PIXEL_SIZE / UV_WORLD_SIZE;
+    var time: f32 = globals.time * params.time_scale + params.time_offset;
 
     let fbm_val_in_x: f32 = UV.x * params.scale + 0.2 * sin(0.3 * time) + 0.15 * time;
     let fbm_val_in_y: f32 = -0.05 * time + UV.y * params.scale + 0.1 * cos(0.68 * time);
@@ -12,6 +12,10 @@ pub struct GameAssets {
     pub small_ship_1: Handle<Image>,
     #[asset(path = "station.png")]
     pub station: Handle<Image>,
+    #[asset(path = "small_station2.png")]
+    pub small_station_2: Handle<Image>,
+    #[asset(path = "small_station3.png")]
+    pub small_station_3: Handle<Image>,
 
     #[asset(path = "cannon_turret.png")]
     pub cannon_turret: Handle<Image>,
@@ -19,9 +23,14 @@ pub struct GameAssets {
     pub rocket_turret: Handle<Image>,
     #[asset(path = "medium_rocket_turret.png")]
     pub medium_rocket_turret: Handle<Image>,
-
-    #[asset(path = "water.png")]
-    pub water: Handle<Image>,
+    #[asset(path = "beam_turret.png")]
+    pub beam_turret: Handle<Image>,
+    #[asset(path = "torpedo_turret.png")]
+    pub torpedo_turret: Handle<Image>,
+    #[asset(path = "mine_layer_turret.png")]
+    pub mine_layer_turret: Handle<Image>,
+    #[asset(path = "wrecked_turret.png")]
+    pub wrecked_turret: Handle<Image>,
 
     #[asset(texture_atlas(tile_size_x = 16.0, tile_size_y = 16.0, columns = 4, rows = 1))]
     #[asset(path = "cannon.png")]
@@ -31,6 +40,8 @@ pub struct GameAssets {
     pub rocket: Handle<Image>,
     #[asset(path = "medium_rocket.png")]
     pub medium_rocket: Handle<Image>,
+    #[asset(path = "mine.png")]
+    pub mine: Handle<Image>,
 
     #[asset(texture_atlas(tile_size_x = 32.0, tile_size_y = 32.0, columns = 8, rows = 1))]
     #[asset(path = "gfx/explosion.png")]
@@ -1,3 +1,7 @@
+/// Torpedoes travel below the surface, out of reach of other projectiles.
+/// Only vessels collide with them, their turrets and the terrain don't.
+pub const TORPEDO_LAYER: u32 = 0b100000;
+pub const TERRAIN_LAYER: u32 = 0b10000;
 pub const PROJECTILE_LAYER: u32 = 0b1000;
 pub const PLAYER_LAYER: u32 = 0b0100;
 pub const ENEMY_LAYER: u32 = 0b0010;
@@ -0,0 +1,328 @@
+use bevy::prelude::*;
+
+use crate::{
+    collision::{ENEMY_LAYER, PROJECTILE_LAYER},
+    mode::GameMode,
+    player::Player,
+    turret::{
+        spawn_turret, trigger_ai_turrets, update_enemy_turret_targets, DestructibleTurrets, Turret,
+        TurretStats, TurretType, TurretWrecked,
+    },
+    ui::health::Health,
+    vessel::{
+        sensor::{update_detection, Detected},
+        ship::{move_ships, steer_towards, BigShip},
+        SpawnVessel,
+    },
+    world::{boundary::ArenaBoundary, camera::CameraShake},
+    GameAssets, GameState, ShipStats,
+};
+
+use super::Enemy;
+
+/// Distance to its destination at which a boss stops steering.
+const BOSS_ARRIVE_RADIUS: f32 = 80.0;
+const BOSS_SPAWN_POSITION: Vec2 = Vec2::new(-4000.0, 3500.0);
+/// Trauma added when a boss enters its next phase.
+const PHASE_CHANGE_TRAUMA: f32 = 0.6;
+
+/// Without a detected player every boss heads back to its home.
+#[derive(Clone, Copy, PartialEq)]
+pub enum BossMovement {
+    /// Circle the player at a fixed distance.
+    Circle { radius: f32 },
+    /// Ram straight into the player.
+    Charge,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BossAttack {
+    /// Every turret fires as soon as it's ready.
+    Sustained,
+    /// Turrets fire for `burst` seconds, then hold fire for `pause` seconds.
+    Salvo { burst: f32, pause: f32 },
+}
+
+#[derive(Clone)]
+pub struct BossPhase {
+    pub name: String,
+    /// The phase starts once health drops to this fraction of the max health.
+    pub health_threshold: f32,
+    /// One entry per turret offset of the hull, like `SpawnVessel.turrets`.
+    pub turrets: Vec<Option<TurretType>>,
+    pub stats_scale: f32,
+    pub movement: BossMovement,
+    pub attack: BossAttack,
+    pub max_speed: f32,
+}
+
+/// Vessel that changes its weapons and tactics as it takes damage.
+/// Phases are ordered by descending `health_threshold`.
+#[derive(Component, Clone)]
+pub struct Boss {
+    pub name: String,
+    pub phases: Vec<BossPhase>,
+    /// `None` until the first phase got set up.
+    pub current: Option<usize>,
+    pub home: Vec2,
+    /// Set once the player's side spotted the boss, from then on it shows up on the HUD.
+    pub engaged: bool,
+    attack_clock: f32,
+}
+
+impl Boss {
+    pub fn new(name: &str, home: Vec2, phases: Vec<BossPhase>) -> Self {
+        Self {
+            name: name.to_string(),
+            phases,
+            current: None,
+            home,
+            engaged: false,
+            attack_clock: 0.0,
+        }
+    }
+
+    pub fn phase(&self) -> Option<&BossPhase> {
+        self.current.and_then(|i| self.phases.get(i))
+    }
+
+    /// The last phase whose threshold has been reached at the given health fraction.
+    fn phase_for(&self, health_fraction: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| health_fraction <= phase.health_threshold)
+            .unwrap_or(0)
+    }
+
+    /// Whether the turrets may fire right now.
+    fn firing(&self) -> bool {
+        match self.phase().map(|phase| phase.attack) {
+            Some(BossAttack::Salvo { burst, pause }) => self.attack_clock % (burst + pause) < burst,
+            _ => true,
+        }
+    }
+}
+
+fn dreadnought_phases() -> Vec<BossPhase> {
+    use TurretType::*;
+
+    vec![
+        BossPhase {
+            name: "Broadside".to_string(),
+            health_threshold: 1.0,
+            turrets: vec![
+                Some(Cannon),
+                Some(Cannon),
+                Some(Cannon),
+                Some(Cannon),
+                Some(Torpedo),
+                Some(Torpedo),
+            ],
+            stats_scale: 1.0,
+            movement: BossMovement::Circle { radius: 900.0 },
+            attack: BossAttack::Sustained,
+            max_speed: 300.0,
+        },
+        BossPhase {
+            name: "Rocket salvos".to_string(),
+            health_threshold: 0.6,
+            turrets: vec![
+                Some(MineLayer),
+                Some(Rocket),
+                Some(MediumRocket),
+                Some(MediumRocket),
+                Some(Cannon),
+                Some(Cannon),
+            ],
+            stats_scale: 1.0,
+            movement: BossMovement::Circle { radius: 1300.0 },
+            attack: BossAttack::Salvo {
+                burst: 2.0,
+                pause: 3.0,
+            },
+            max_speed: 350.0,
+        },
+        BossPhase {
+            name: "Last stand".to_string(),
+            health_threshold: 0.25,
+            turrets: vec![
+                Some(MediumRocket),
+                Some(MediumRocket),
+                Some(Rocket),
+                Some(Rocket),
+                Some(Beam),
+                Some(Beam),
+            ],
+            stats_scale: 1.5,
+            movement: BossMovement::Charge,
+            attack: BossAttack::Salvo {
+                burst: 3.0,
+                pause: 1.5,
+            },
+            max_speed: 600.0,
+        },
+    ]
+}
+
+fn spawn_boss(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    boundary: Res<ArenaBoundary>,
+    mut ev_spawn_vessel: EventWriter<SpawnVessel>,
+) {
+    let home = boundary.clamp_inside(BOSS_SPAWN_POSITION, 1000.0);
+    let entity = commands
+        .spawn((
+            Enemy::default(),
+            BigShip::new(&assets, ENEMY_LAYER, PROJECTILE_LAYER),
+            Boss::new("Dreadnought", home, dreadnought_phases()),
+            DestructibleTurrets { health: 400.0 },
+        ))
+        .insert(Transform::from_translation(home.extend(0.0)))
+        .id();
+    // Turrets are spawned by the boss phases.
+    ev_spawn_vessel.send(SpawnVessel {
+        entity,
+        stats_scale: 1.0,
+        turrets: Vec::new(),
+        health: Health::new(entity, 8000.0, 4.0),
+    });
+}
+
+/// Enter the next phase once health drops below its threshold,
+/// refitting every mount that hasn't been wrecked.
+fn advance_boss_phases(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_bosses: Query<(Entity, &mut Boss, &Health, &TurretStats, &mut ShipStats)>,
+    q_turrets: Query<(Entity, &Turret, Has<TurretWrecked>)>,
+    mut ev_camera_shake: EventWriter<CameraShake>,
+) {
+    for (entity, mut boss, health, turret_stats, mut ship_stats) in &mut q_bosses {
+        let fraction = (health.health / health.max_health).clamp(0.0, 1.0);
+        let next = boss.phase_for(fraction);
+        if boss.current.is_some_and(|current| current >= next) {
+            continue;
+        }
+
+        if boss.current.is_some() {
+            ev_camera_shake.send(CameraShake {
+                trauma: PHASE_CHANGE_TRAUMA,
+            });
+        }
+        boss.current = Some(next);
+        boss.attack_clock = 0.0;
+
+        let mut wrecked_offsets = Vec::new();
+        for (turret_entity, turret, wrecked) in &q_turrets {
+            if turret.source != entity {
+                continue;
+            }
+            if wrecked {
+                wrecked_offsets.push(turret.offset.truncate());
+            } else {
+                commands.entity(turret_entity).despawn_recursive();
+            }
+        }
+
+        let phase = boss.phases[next].clone();
+        ship_stats.max_speed = phase.max_speed;
+        for (turret_type, offset) in phase.turrets.iter().zip(&turret_stats.turret_offsets) {
+            let turret_type = match turret_type {
+                Some(t) => *t,
+                None => continue,
+            };
+            if wrecked_offsets.contains(offset) {
+                continue;
+            }
+            spawn_turret(
+                &mut commands,
+                &assets,
+                Turret::new(turret_type, phase.stats_scale, entity, *offset),
+            );
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn steer_bosses(
+    time: Res<Time>,
+    mut q_bosses: Query<(&Transform, &mut ShipStats, &mut Boss, &Detected)>,
+    q_player: Query<(&Transform, &Detected), (With<Player>, Without<Boss>)>,
+) {
+    let player = match q_player.get_single() {
+        Ok((transform, detected)) if detected.0 => Some(transform.translation.truncate()),
+        _ => None,
+    };
+
+    for (transform, mut ship_stats, mut boss, detected) in &mut q_bosses {
+        boss.engaged |= detected.0;
+
+        let pos = transform.translation.truncate();
+        let movement = match boss.phase() {
+            Some(phase) => phase.movement,
+            None => continue,
+        };
+        let destination = match (movement, player) {
+            (BossMovement::Circle { radius }, Some(player_pos)) => {
+                let offset = (pos - player_pos).normalize_or_zero();
+                player_pos + Vec2::from_angle(0.5).rotate(offset) * radius
+            }
+            (BossMovement::Charge, Some(player_pos)) => player_pos,
+            _ => boss.home,
+        };
+
+        if pos.distance(destination) < BOSS_ARRIVE_RADIUS {
+            ship_stats.current_steering_direction = 0.0;
+            continue;
+        }
+        steer_towards(
+            transform,
+            &mut ship_stats,
+            destination,
+            time.delta_seconds(),
+        );
+    }
+}
+
+/// Hold fire between salvos.
+fn pace_boss_attacks(
+    time: Res<Time>,
+    mut q_bosses: Query<(Entity, &mut Boss)>,
+    mut q_turrets: Query<&mut Turret>,
+) {
+    for (entity, mut boss) in &mut q_bosses {
+        boss.attack_clock += time.delta_seconds();
+        if boss.firing() {
+            continue;
+        }
+
+        for mut turret in &mut q_turrets {
+            if turret.source == entity {
+                turret.has_target = false;
+            }
+        }
+    }
+}
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Gaming),
+            spawn_boss.run_if(resource_equals(GameMode::Skirmish)),
+        )
+        .add_systems(
+            Update,
+            (
+                advance_boss_phases,
+                steer_bosses.after(update_detection).before(move_ships),
+                pace_boss_attacks
+                    .after(update_enemy_turret_targets)
+                    .before(trigger_ai_turrets),
+            )
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
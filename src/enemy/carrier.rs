@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    collision::{ENEMY_LAYER, PROJECTILE_LAYER},
+    turret::TurretType,
+    ui::health::Health,
+    utils::quat_from_vec2,
+    vessel::{
+        ship::{move_ships, SmallShip1},
+        SpawnVessel,
+    },
+    GameAssets, GameState, ShipStats,
+};
+
+use super::ship_ai::{AiDisabled, ShipAi};
+use super::Enemy;
+
+const LAUNCH_DURATION: f32 = 1.2;
+/// Distance a ship travels out of the hangar during its launch.
+const LAUNCH_DISTANCE: f32 = 150.0;
+const LAUNCH_START_SCALE: f32 = 0.3;
+
+/// Station that keeps launching small ships while it's alive.
+#[derive(Component, Clone)]
+pub struct Carrier {
+    /// Local offset of the hangar ships launch from.
+    pub hangar_offset: Vec2,
+    pub launch_timer: Timer,
+    /// Launches are skipped while this many ships from this carrier are alive.
+    pub max_children: usize,
+}
+
+impl Carrier {
+    pub fn new(hangar_offset: Vec2, launch_interval: f32, max_children: usize) -> Self {
+        Self {
+            hangar_offset,
+            launch_timer: Timer::new(
+                Duration::from_secs_f32(launch_interval),
+                TimerMode::Repeating,
+            ),
+            max_children,
+        }
+    }
+}
+
+/// A ship launched by a carrier.
+#[derive(Component)]
+pub struct CarrierChild {
+    pub carrier: Entity,
+}
+
+#[derive(Component)]
+struct Launching {
+    timer: Timer,
+    start: Vec3,
+    direction: Vec3,
+}
+
+fn launch_ships(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<GameAssets>,
+    mut q_carriers: Query<(Entity, &Transform, &mut Carrier)>,
+    q_children: Query<&CarrierChild>,
+    mut ev_spawn_vessel: EventWriter<SpawnVessel>,
+) {
+    for (carrier_entity, carrier_transform, mut carrier) in &mut q_carriers {
+        carrier.launch_timer.tick(time.delta());
+        if !carrier.launch_timer.just_finished() {
+            continue;
+        }
+
+        let children = q_children
+            .iter()
+            .filter(|child| child.carrier == carrier_entity)
+            .count();
+        if children >= carrier.max_children {
+            continue;
+        }
+
+        let start = carrier_transform.translation
+            + carrier_transform
+                .rotation
+                .mul_vec3(carrier.hangar_offset.extend(0.0))
+            + Vec3::Z;
+        let direction = match carrier_transform
+            .rotation
+            .mul_vec3(carrier.hangar_offset.extend(0.0))
+            .try_normalize()
+        {
+            Some(d) => d,
+            None => carrier_transform.local_y(),
+        };
+        let transform = Transform::from_translation(start)
+            .with_rotation(quat_from_vec2(direction.truncate()))
+            .with_scale(Vec3::splat(LAUNCH_START_SCALE));
+
+        let entity = commands
+            .spawn((
+                Enemy::default(),
+                SmallShip1::new(&assets, ENEMY_LAYER, PROJECTILE_LAYER),
+                ShipAi {
+                    anchor: Some(carrier_entity),
+                    ..default()
+                },
+                AiDisabled,
+                CarrierChild {
+                    carrier: carrier_entity,
+                },
+                Launching {
+                    timer: Timer::from_seconds(LAUNCH_DURATION, TimerMode::Once),
+                    start,
+                    direction,
+                },
+            ))
+            .insert(transform)
+            .id();
+        ev_spawn_vessel.send(SpawnVessel {
+            entity,
+            stats_scale: 1.0,
+            turrets: vec![Some(TurretType::Cannon)],
+            health: Health::new(entity, 200.0, 1.0),
+        });
+    }
+}
+
+/// Ships slide out of the hangar while growing to full size,
+/// the AI takes over once they're out.
+fn animate_launches(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_launching: Query<(Entity, &mut Transform, &mut ShipStats, &mut Launching)>,
+) {
+    for (entity, mut transform, mut ship_stats, mut launching) in &mut q_launching {
+        launching.timer.tick(time.delta());
+        let t = launching.timer.percent();
+        let eased = 1.0 - (1.0 - t) * (1.0 - t);
+
+        transform.translation = launching.start + launching.direction * LAUNCH_DISTANCE * eased;
+        transform.scale = Vec3::splat(LAUNCH_START_SCALE + (1.0 - LAUNCH_START_SCALE) * eased);
+        ship_stats.acceleration = Vec2::ZERO;
+
+        if launching.timer.finished() {
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<(Launching, AiDisabled)>();
+        }
+    }
+}
+
+pub struct CarrierPlugin;
+
+impl Plugin for CarrierPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (launch_ships, animate_launches.after(move_ships)).run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -1,10 +1,20 @@
+pub mod boss;
+pub mod carrier;
+pub mod ship_ai;
+
 use bevy::prelude::*;
 
 use crate::{
     collision::{ENEMY_LAYER, PROJECTILE_LAYER},
-    turret::TurretType,
+    enemy::carrier::Carrier,
+    turret::{DestructibleTurrets, Turret, TurretType},
     ui::health::Health,
-    vessel::{station::SmallStation1, SpawnVessel},
+    vessel::{
+        section::HullSection,
+        station::{SmallStation1, SmallStation2, SmallStation3},
+        SpawnVessel,
+    },
+    world::boundary::ArenaBoundary,
     GameAssets, GameState,
 };
 
@@ -12,7 +22,12 @@ pub struct GuardianEnemyPlugin;
 
 impl Plugin for GuardianEnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_plugins((
+            boss::BossPlugin,
+            carrier::CarrierPlugin,
+            ship_ai::ShipAiPlugin,
+        ))
+        .add_systems(
             Update,
             (despawn_enemies).run_if(in_state(GameState::Gaming)),
         )
@@ -23,12 +38,24 @@ impl Plugin for GuardianEnemyPlugin {
 #[derive(Component, Default)]
 pub struct Enemy {}
 
+/// Vessels enemies go after before the player, like convoy ships.
+#[derive(Component, Default)]
+pub struct HighValueTarget;
+
+/// Keep spawned enemies this far away from the arena boundary.
+const SPAWN_BOUNDARY_MARGIN: f32 = 500.0;
+
 fn spawn_dummy_enemy(
     mut commands: Commands,
     assets: Res<GameAssets>,
+    boundary: Res<ArenaBoundary>,
     mut ev_spawn_vessel: EventWriter<SpawnVessel>,
 ) {
-    let transform = Transform::from_translation(Vec3::new(-500.0, -500.0, 0.0));
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(-500.0, -500.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
     let entity = commands
         .spawn((
             Enemy::default(),
@@ -42,7 +69,11 @@ fn spawn_dummy_enemy(
         turrets: vec![Some(TurretType::Rocket)],
         health: Health::new(entity, 1000.0, 2.0),
     });
-    let transform = Transform::from_translation(Vec3::new(500.0, -500.0, 0.0));
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(500.0, -500.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
     let entity = commands
         .spawn((
             Enemy::default(),
@@ -56,7 +87,11 @@ fn spawn_dummy_enemy(
         turrets: vec![Some(TurretType::Cannon)],
         health: Health::new(entity, 1000.0, 2.0),
     });
-    let transform = Transform::from_translation(Vec3::new(500.0, 500.0, 0.0));
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(500.0, 500.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
     let entity = commands
         .spawn((
             Enemy::default(),
@@ -70,7 +105,11 @@ fn spawn_dummy_enemy(
         turrets: vec![Some(TurretType::MediumRocket)],
         health: Health::new(entity, 1000.0, 2.0),
     });
-    let transform = Transform::from_translation(Vec3::new(-500.0, 500.0, 0.0));
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(-500.0, 500.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
     let entity = commands
         .spawn((
             Enemy::default(),
@@ -84,9 +123,83 @@ fn spawn_dummy_enemy(
         turrets: vec![Some(TurretType::MediumRocket)],
         health: Health::new(entity, 1000.0, 2.0),
     });
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(0.0, 1800.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
+    let entity = commands
+        .spawn((
+            Enemy::default(),
+            SmallStation2::new(&assets, ENEMY_LAYER, PROJECTILE_LAYER),
+            DestructibleTurrets { health: 300.0 },
+        ))
+        .insert(transform)
+        .id();
+    ev_spawn_vessel.send(SpawnVessel {
+        entity,
+        stats_scale: 1.0,
+        turrets: vec![
+            Some(TurretType::Cannon),
+            Some(TurretType::Rocket),
+            Some(TurretType::Rocket),
+            Some(TurretType::Cannon),
+        ],
+        health: Health::new(entity, 2500.0, 4.0),
+    });
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(0.0, -1800.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
+    let entity = commands
+        .spawn((
+            Enemy::default(),
+            SmallStation3::new(&assets, ENEMY_LAYER, PROJECTILE_LAYER),
+            DestructibleTurrets { health: 300.0 },
+        ))
+        .insert(transform)
+        .id();
+    ev_spawn_vessel.send(SpawnVessel {
+        entity,
+        stats_scale: 1.0,
+        turrets: vec![
+            Some(TurretType::Cannon),
+            Some(TurretType::MediumRocket),
+            Some(TurretType::Cannon),
+            Some(TurretType::Rocket),
+            Some(TurretType::Rocket),
+        ],
+        health: Health::new(entity, 4000.0, 6.0),
+    });
+    let transform = Transform::from_translation(
+        boundary
+            .clamp_inside(Vec2::new(2500.0, 0.0), SPAWN_BOUNDARY_MARGIN)
+            .extend(0.0),
+    );
+    let entity = commands
+        .spawn((
+            Enemy::default(),
+            SmallStation3::new(&assets, ENEMY_LAYER, PROJECTILE_LAYER),
+            Carrier::new(Vec2::new(0.0, -130.0), 8.0, 4),
+            DestructibleTurrets { health: 300.0 },
+        ))
+        .insert(transform)
+        .id();
+    ev_spawn_vessel.send(SpawnVessel {
+        entity,
+        stats_scale: 1.0,
+        turrets: vec![Some(TurretType::Cannon), None, Some(TurretType::Cannon)],
+        health: Health::new(entity, 3000.0, 6.0),
+    });
 }
 
-fn despawn_enemies(mut commands: Commands, q_enemies: Query<(Entity, &Health)>) {
+/// Turrets and hull sections with their own health are wrecked instead of despawned.
+#[allow(clippy::type_complexity)]
+fn despawn_enemies(
+    mut commands: Commands,
+    q_enemies: Query<(Entity, &Health), (Without<Turret>, Without<HullSection>)>,
+) {
     for (entity, health) in &q_enemies {
         if health.health <= 0.0 {
             commands.entity(entity).despawn_recursive();
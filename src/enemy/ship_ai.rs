@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+use crate::{
+    enemy::HighValueTarget,
+    player::Player,
+    vessel::{
+        sensor::{update_detection, Detected},
+        ship::{move_ships, steer_towards, update_dash, DashEnergy},
+    },
+    GameState, ShipStats,
+};
+
+/// Distance to its destination from which an attacking ship dashes to close in.
+const DASH_DISTANCE: f32 = 900.0;
+/// How closely a ship has to face its destination before it dashes, as a dot product.
+const DASH_ALIGNMENT: f32 = 0.95;
+
+/// Simple AI for enemy ships. Attacks detected high value targets or the player,
+/// otherwise circles its anchor, e.g. the carrier it launched from.
+#[derive(Component, Clone)]
+pub struct ShipAi {
+    pub anchor: Option<Entity>,
+    /// Distance the ship tries to keep to its target while attacking.
+    pub attack_distance: f32,
+    /// Distance the ship circles its anchor at.
+    pub patrol_radius: f32,
+}
+
+impl Default for ShipAi {
+    fn default() -> Self {
+        Self {
+            anchor: None,
+            attack_distance: 600.0,
+            patrol_radius: 400.0,
+        }
+    }
+}
+
+/// Pauses the AI, for example while a ship is still being launched.
+#[derive(Component)]
+pub struct AiDisabled;
+
+/// The point a ship should head for this frame.
+fn ai_destination(ai: &ShipAi, pos: Vec2, target: Option<Vec2>, anchor: Option<Vec2>) -> Vec2 {
+    if let Some(target_pos) = target {
+        let away = (pos - target_pos).normalize_or_zero();
+        // Head for a point next to the target so ships strafe instead of ramming.
+        return target_pos + away.perp() * ai.attack_distance;
+    }
+
+    match anchor {
+        Some(anchor_pos) => {
+            let offset = (pos - anchor_pos).normalize_or_zero();
+            anchor_pos + Vec2::from_angle(0.6).rotate(offset) * ai.patrol_radius
+        }
+        None => pos,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn steer_ai_ships(
+    time: Res<Time>,
+    mut q_ships: Query<
+        (&Transform, &mut ShipStats, &ShipAi, Option<&mut DashEnergy>),
+        Without<AiDisabled>,
+    >,
+    q_player: Query<(&Transform, &Detected), With<Player>>,
+    q_priority: Query<(&Transform, &Detected), (With<HighValueTarget>, Without<ShipAi>)>,
+    q_anchors: Query<&Transform, Without<ShipAi>>,
+) {
+    let player = match q_player.get_single() {
+        Ok((transform, detected)) if detected.0 => Some(transform.translation.truncate()),
+        _ => None,
+    };
+    let priority: Vec<Vec2> = q_priority
+        .iter()
+        .filter(|(_, detected)| detected.0)
+        .map(|(t, _)| t.translation.truncate())
+        .collect();
+
+    for (transform, mut ship_stats, ai, dash_energy) in &mut q_ships {
+        let pos = transform.translation.truncate();
+        let anchor = ai
+            .anchor
+            .and_then(|anchor| q_anchors.get(anchor).ok())
+            .map(|t| t.translation.truncate());
+        let target = priority
+            .iter()
+            .copied()
+            .min_by(|a, b| a.distance(pos).total_cmp(&b.distance(pos)))
+            .or(player);
+        let destination = ai_destination(ai, pos, target, anchor);
+
+        let to_destination = destination - pos;
+        // Dash rules are the same as for the player, the AI only decides when to ask.
+        if let Some(mut dash_energy) = dash_energy {
+            let facing = transform
+                .local_y()
+                .truncate()
+                .dot(to_destination.normalize_or_zero());
+            dash_energy.requested = target.is_some()
+                && to_destination.length() > DASH_DISTANCE
+                && facing > DASH_ALIGNMENT;
+        }
+
+        if to_destination.length() < 50.0 {
+            ship_stats.current_steering_direction = 0.0;
+            continue;
+        }
+
+        steer_towards(
+            transform,
+            &mut ship_stats,
+            destination,
+            time.delta_seconds(),
+        );
+    }
+}
+
+pub struct ShipAiPlugin;
+
+impl Plugin for ShipAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (steer_ai_ships
+                .after(update_detection)
+                .before(update_dash)
+                .before(move_ships),)
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -8,6 +8,7 @@ use bevy_rapier2d::prelude::*;
 mod assets;
 mod collision;
 mod enemy;
+mod mode;
 mod player;
 mod projectile;
 mod turret;
@@ -58,18 +59,9 @@ fn main() {
             turret::TurretPlugin,
             vessel::GuardianVesselPlugin,
             enemy::GuardianEnemyPlugin,
+            mode::GameModePlugin,
             player::GuardianPlayerPlugin,
         ))
         .insert_resource(ClearColor(Color::MIDNIGHT_BLUE))
-        .add_systems(OnEnter(GameState::Gaming), spawn_water_tiles)
         .run();
 }
-
-fn spawn_water_tiles(mut commands: Commands, assets: Res<GameAssets>) {
-    commands.spawn((SpriteBundle {
-        texture: assets.water.clone(),
-        transform: Transform::from_translation(Vec3::new(0.0, 0.0, -100.0))
-            .with_scale(Vec3::splat(10.0)),
-        ..default()
-    },));
-}
@@ -0,0 +1,301 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{CollisionGroups, Group};
+
+use crate::{
+    collision::PROJECTILE_LAYER,
+    enemy::{ship_ai::ShipAi, Enemy},
+    turret::{TurretStats, TurretType},
+    ui::{health::Health, objective::ObjectiveText},
+    vessel::{
+        station::{SmallStation1, StationStats},
+        SpawnVessel,
+    },
+    world::terrain::TerrainConfig,
+    GameAssets, GameState,
+};
+
+use super::{GameMode, Side};
+
+const NEUTRAL_COLOR: Color = Color::rgb(0.8, 0.8, 0.8);
+const PLAYER_COLOR: Color = Color::rgb(0.2, 0.9, 0.3);
+const ENEMY_COLOR: Color = Color::rgb(0.9, 0.15, 0.15);
+
+#[derive(Resource, Clone)]
+pub struct ControlPointConfig {
+    pub zones: Vec<Vec2>,
+    pub radius: f32,
+    /// Seconds a side needs to hold an uncontested zone to capture it.
+    pub capture_time: f32,
+    /// Seconds a side needs to hold the majority of zones to win.
+    pub win_time: f32,
+}
+
+impl Default for ControlPointConfig {
+    fn default() -> Self {
+        Self {
+            zones: vec![
+                Vec2::new(0.0, 3500.0),
+                Vec2::new(-3500.0, 0.0),
+                Vec2::new(3500.0, 2500.0),
+                Vec2::new(0.0, -3500.0),
+                Vec2::new(3500.0, -2500.0),
+            ],
+            radius: 600.0,
+            capture_time: 10.0,
+            win_time: 90.0,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ControlPoint {
+    pub owner: Option<Side>,
+    /// -1 is fully captured by the enemy, 1 by the player.
+    pub progress: f32,
+    /// The station spawned when the zone was first captured.
+    pub station: Option<Entity>,
+}
+
+#[derive(Resource, Default)]
+pub struct ControlScore {
+    pub player_hold: f32,
+    pub enemy_hold: f32,
+    pub winner: Option<Side>,
+}
+
+fn side_color(side: Option<Side>) -> Color {
+    match side {
+        None => NEUTRAL_COLOR,
+        Some(Side::Player) => PLAYER_COLOR,
+        Some(Side::Enemy) => ENEMY_COLOR,
+    }
+}
+
+/// Keep terrain out of the zones so they can always be reached.
+fn reserve_zone_space(config: Res<ControlPointConfig>, mut terrain: ResMut<TerrainConfig>) {
+    terrain.spawn_points.extend(config.zones.iter().copied());
+}
+
+fn spawn_control_points(mut commands: Commands, config: Res<ControlPointConfig>) {
+    for zone in &config.zones {
+        commands.spawn((
+            ControlPoint {
+                owner: None,
+                progress: 0.0,
+                station: None,
+            },
+            SpatialBundle::from_transform(Transform::from_translation(zone.extend(0.0))),
+        ));
+    }
+}
+
+/// Zone stations don't count, a zone flips once its defenders are gone.
+#[allow(clippy::type_complexity)]
+fn update_capture(
+    time: Res<Time>,
+    config: Res<ControlPointConfig>,
+    mut q_points: Query<(&Transform, &mut ControlPoint)>,
+    q_vessels: Query<(&Transform, Has<Enemy>), (With<TurretStats>, Without<StationStats>)>,
+) {
+    for (transform, mut point) in &mut q_points {
+        let center = transform.translation.truncate();
+        let (mut friendly, mut hostile) = (false, false);
+        for (vessel_transform, is_enemy) in &q_vessels {
+            if vessel_transform.translation.truncate().distance(center) > config.radius {
+                continue;
+            }
+            if is_enemy {
+                hostile = true;
+            } else {
+                friendly = true;
+            }
+        }
+
+        let direction = match (friendly, hostile) {
+            (true, false) => 1.0,
+            (false, true) => -1.0,
+            _ => continue,
+        };
+        point.progress = (point.progress + direction * time.delta_seconds() / config.capture_time)
+            .clamp(-1.0, 1.0);
+    }
+}
+
+fn claim_control_points(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_points: Query<(&Transform, &mut ControlPoint)>,
+    mut q_stations: Query<(&mut CollisionGroups, &mut Sprite)>,
+    mut ev_spawn_vessel: EventWriter<SpawnVessel>,
+) {
+    for (transform, mut point) in &mut q_points {
+        if let Some(station) = point.station {
+            if q_stations.get(station).is_err() {
+                // The station got destroyed, the zone is up for grabs again.
+                point.station = None;
+                point.owner = None;
+                point.progress = 0.0;
+                continue;
+            }
+        }
+
+        let side = if point.progress >= 1.0 {
+            Side::Player
+        } else if point.progress <= -1.0 {
+            Side::Enemy
+        } else {
+            continue;
+        };
+        if point.owner == Some(side) {
+            continue;
+        }
+        point.owner = Some(side);
+
+        if let Some(station) = point.station {
+            let (mut groups, mut sprite) = match q_stations.get_mut(station) {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+            groups.memberships = Group::from_bits(side.collision_layer()).unwrap();
+            sprite.color = Color::WHITE;
+            match side {
+                Side::Player => commands.entity(station).remove::<Enemy>(),
+                Side::Enemy => commands.entity(station).insert(Enemy::default()),
+            };
+            continue;
+        }
+
+        let mut station = commands.spawn(SmallStation1::new(
+            &assets,
+            side.collision_layer(),
+            PROJECTILE_LAYER,
+        ));
+        station.insert(*transform);
+        if side == Side::Enemy {
+            station.insert(Enemy::default());
+        }
+        let entity = station.id();
+        ev_spawn_vessel.send(SpawnVessel {
+            entity,
+            stats_scale: 1.0,
+            turrets: vec![Some(TurretType::Rocket)],
+            health: Health::new(entity, 1000.0, 2.0),
+        });
+        point.station = Some(entity);
+    }
+}
+
+fn update_score(
+    time: Res<Time>,
+    config: Res<ControlPointConfig>,
+    q_points: Query<&ControlPoint>,
+    mut score: ResMut<ControlScore>,
+    mut objective: ResMut<ObjectiveText>,
+) {
+    if let Some(winner) = score.winner {
+        let message = match winner {
+            Side::Player => "Victory! You hold the sea.",
+            Side::Enemy => "Defeat. The enemy holds the sea.",
+        };
+        if objective.0 != message {
+            objective.0 = message.to_string();
+        }
+        return;
+    }
+
+    let owned = |side: Side| q_points.iter().filter(|p| p.owner == Some(side)).count();
+    let (player_zones, enemy_zones) = (owned(Side::Player), owned(Side::Enemy));
+    let majority = config.zones.len() / 2 + 1;
+
+    if player_zones >= majority {
+        score.player_hold += time.delta_seconds();
+    } else if enemy_zones >= majority {
+        score.enemy_hold += time.delta_seconds();
+    }
+
+    if score.player_hold >= config.win_time {
+        score.winner = Some(Side::Player);
+    } else if score.enemy_hold >= config.win_time {
+        score.winner = Some(Side::Enemy);
+    }
+
+    objective.0 = format!(
+        "Zones  You {} - {} Enemy    Hold {:.0}s - {:.0}s of {:.0}s",
+        player_zones, enemy_zones, score.player_hold, score.enemy_hold, config.win_time
+    );
+}
+
+/// Send enemy ships to the closest zone their side doesn't own yet.
+fn direct_enemy_ships(
+    mut q_ships: Query<(&Transform, &mut ShipAi), With<Enemy>>,
+    q_points: Query<(Entity, &Transform, &ControlPoint)>,
+) {
+    for (transform, mut ai) in &mut q_ships {
+        let pos = transform.translation.truncate();
+        let distance = |t: &Transform| t.translation.truncate().distance(pos);
+
+        let target = q_points
+            .iter()
+            .filter(|(_, _, point)| point.owner != Some(Side::Enemy))
+            .min_by(|a, b| distance(a.1).total_cmp(&distance(b.1)))
+            .or_else(|| {
+                q_points
+                    .iter()
+                    .min_by(|a, b| distance(a.1).total_cmp(&distance(b.1)))
+            });
+
+        if let Some((entity, _, _)) = target {
+            ai.anchor = Some(entity);
+        }
+    }
+}
+
+fn draw_control_points(
+    mut gizmos: Gizmos,
+    config: Res<ControlPointConfig>,
+    q_points: Query<(&Transform, &ControlPoint)>,
+) {
+    for (transform, point) in &q_points {
+        let center = transform.translation.truncate();
+        gizmos.circle_2d(center, config.radius, side_color(point.owner));
+
+        if point.progress != 0.0 {
+            let leading = Side::from_is_enemy(point.progress < 0.0);
+            gizmos.circle_2d(
+                center,
+                config.radius * point.progress.abs(),
+                side_color(Some(leading)),
+            );
+        }
+    }
+}
+
+pub struct ControlPointsPlugin;
+
+impl Plugin for ControlPointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ControlPointConfig>()
+            .init_resource::<ControlScore>()
+            .add_systems(
+                Startup,
+                reserve_zone_space.run_if(resource_equals(GameMode::ControlPoints)),
+            )
+            .add_systems(
+                OnEnter(GameState::Gaming),
+                spawn_control_points.run_if(resource_equals(GameMode::ControlPoints)),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_capture,
+                    claim_control_points,
+                    update_score,
+                    direct_enemy_ships,
+                    draw_control_points,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Gaming))
+                    .run_if(resource_equals(GameMode::ControlPoints)),
+            );
+    }
+}
@@ -0,0 +1,276 @@
+use bevy::prelude::*;
+
+use crate::{
+    collision::{ENEMY_LAYER, PLAYER_LAYER, PROJECTILE_LAYER},
+    enemy::{ship_ai::ShipAi, Enemy, HighValueTarget},
+    turret::TurretType,
+    ui::{health::Health, objective::ObjectiveText},
+    utils::quat_from_vec2,
+    vessel::{
+        ship::{route::RouteFollower, MerchantShip, SmallShip1},
+        SpawnVessel,
+    },
+    world::terrain::TerrainConfig,
+    GameAssets, GameState,
+};
+
+use super::GameMode;
+
+/// Distance between the points along the route that are kept clear of terrain.
+const ROUTE_CLEARANCE_STEP: f32 = 300.0;
+/// Score for a convoy ship arriving without a scratch.
+const SCORE_PER_SHIP: f32 = 1000.0;
+const ROUTE_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.3);
+
+#[derive(Resource, Clone)]
+pub struct ConvoyConfig {
+    pub route: Vec<Vec2>,
+    pub ships: usize,
+    /// Distance between convoy ships at the start.
+    pub spacing: f32,
+    pub ship_health: f32,
+    /// Places along the route where enemy ships lie in wait.
+    pub ambushes: Vec<Vec2>,
+    pub ambush_size: usize,
+}
+
+impl Default for ConvoyConfig {
+    fn default() -> Self {
+        Self {
+            route: vec![
+                Vec2::new(-800.0, -800.0),
+                Vec2::new(-2500.0, -3000.0),
+                Vec2::new(1000.0, -4500.0),
+                Vec2::new(4500.0, -2000.0),
+                Vec2::new(5000.0, 2500.0),
+                Vec2::new(2500.0, 5500.0),
+            ],
+            ships: 5,
+            spacing: 120.0,
+            ship_health: 600.0,
+            ambushes: vec![
+                Vec2::new(-1500.0, -4200.0),
+                Vec2::new(3500.0, -3800.0),
+                Vec2::new(5800.0, 500.0),
+            ],
+            ambush_size: 2,
+        }
+    }
+}
+
+/// A merchant ship of the convoy the player has to protect.
+#[derive(Component)]
+pub struct ConvoyShip;
+
+#[derive(Resource, Default)]
+pub struct ConvoyMission {
+    /// Remaining hull, 0 to 1, of every ship that reached the destination.
+    pub arrived: Vec<f32>,
+    pub lost: usize,
+    pub finished: bool,
+}
+
+impl ConvoyMission {
+    pub fn score(&self) -> u32 {
+        (self.arrived.iter().sum::<f32>() * SCORE_PER_SHIP).round() as u32
+    }
+
+    /// At least half of the convoy has to make it.
+    pub fn success(&self, total: usize) -> bool {
+        self.arrived.len() * 2 >= total
+    }
+}
+
+#[derive(Component)]
+struct ConvoyResultScreen;
+
+/// Keep terrain off the route so the convoy can't get stuck on an island.
+fn reserve_route_space(config: Res<ConvoyConfig>, mut terrain: ResMut<TerrainConfig>) {
+    for segment in config.route.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let steps = (start.distance(end) / ROUTE_CLEARANCE_STEP).ceil() as usize;
+        for i in 0..=steps {
+            terrain
+                .spawn_points
+                .push(start.lerp(end, i as f32 / steps.max(1) as f32));
+        }
+    }
+    terrain.spawn_points.extend(config.ambushes.iter().copied());
+}
+
+fn spawn_convoy(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    config: Res<ConvoyConfig>,
+    mut ev_spawn_vessel: EventWriter<SpawnVessel>,
+) {
+    let (start, next) = match (config.route.first(), config.route.get(1)) {
+        (Some(s), Some(n)) => (*s, *n),
+        _ => return,
+    };
+    let direction = (next - start).normalize_or_zero();
+
+    for i in 0..config.ships {
+        let position = start - direction * config.spacing * i as f32;
+        let transform = Transform::from_translation(position.extend(0.0))
+            .with_rotation(quat_from_vec2(direction));
+        let entity = commands
+            .spawn((
+                ConvoyShip,
+                HighValueTarget,
+                MerchantShip::new(&assets, PLAYER_LAYER, PROJECTILE_LAYER),
+                RouteFollower::new(config.route.clone()),
+            ))
+            .insert(transform)
+            .id();
+        ev_spawn_vessel.send(SpawnVessel {
+            entity,
+            stats_scale: 1.0,
+            turrets: vec![None],
+            health: Health::new(entity, config.ship_health, 1.0),
+        });
+    }
+
+    for ambush in &config.ambushes {
+        for i in 0..config.ambush_size {
+            let offset = Vec2::from_angle(i as f32 * 2.0) * 150.0;
+            let entity = commands
+                .spawn((
+                    Enemy::default(),
+                    SmallShip1::new(&assets, ENEMY_LAYER, PROJECTILE_LAYER),
+                    ShipAi::default(),
+                ))
+                .insert(Transform::from_translation((*ambush + offset).extend(0.0)))
+                .id();
+            ev_spawn_vessel.send(SpawnVessel {
+                entity,
+                stats_scale: 1.0,
+                turrets: vec![Some(TurretType::Cannon)],
+                health: Health::new(entity, 200.0, 1.0),
+            });
+        }
+    }
+}
+
+/// Convoy ships that reach the destination leave the map and count towards the score.
+fn track_convoy(
+    mut commands: Commands,
+    config: Res<ConvoyConfig>,
+    mut mission: ResMut<ConvoyMission>,
+    mut objective: ResMut<ObjectiveText>,
+    q_convoy: Query<(Entity, &RouteFollower, Option<&Health>), With<ConvoyShip>>,
+) {
+    if mission.finished {
+        return;
+    }
+
+    let mut alive = 0;
+    for (entity, route, health) in &q_convoy {
+        if !route.finished() {
+            alive += 1;
+            continue;
+        }
+
+        let hull = health.map_or(1.0, |h| (h.health / h.max_health).clamp(0.0, 1.0));
+        mission.arrived.push(hull);
+        commands.entity(entity).despawn_recursive();
+    }
+
+    mission.lost = config.ships - alive - mission.arrived.len();
+    mission.finished = alive == 0;
+
+    objective.0 = format!(
+        "Escort the convoy    {} sailing, {} arrived, {} lost",
+        alive,
+        mission.arrived.len(),
+        mission.lost
+    );
+}
+
+fn show_result_screen(
+    mut commands: Commands,
+    config: Res<ConvoyConfig>,
+    mission: Res<ConvoyMission>,
+    q_screen: Query<(), With<ConvoyResultScreen>>,
+) {
+    if !mission.finished || !q_screen.is_empty() {
+        return;
+    }
+
+    let title = if mission.success(config.ships) {
+        "Mission complete"
+    } else {
+        "Mission failed"
+    };
+    let lines = [
+        title.to_string(),
+        format!(
+            "Ships arrived: {} / {}",
+            mission.arrived.len(),
+            config.ships
+        ),
+        format!("Ships lost: {}", mission.lost),
+        format!("Score: {}", mission.score()),
+    ];
+
+    commands
+        .spawn((
+            ConvoyResultScreen,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                ..default()
+            },
+        ))
+        .with_children(|root| {
+            for (i, line) in lines.into_iter().enumerate() {
+                root.spawn(TextBundle::from_section(
+                    line,
+                    TextStyle {
+                        font_size: if i == 0 { 40.0 } else { 24.0 },
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ));
+            }
+        });
+}
+
+fn draw_route(mut gizmos: Gizmos, config: Res<ConvoyConfig>) {
+    gizmos.linestrip_2d(config.route.iter().copied(), ROUTE_COLOR);
+    if let Some(destination) = config.route.last() {
+        gizmos.circle_2d(*destination, 200.0, ROUTE_COLOR);
+    }
+}
+
+pub struct ConvoyPlugin;
+
+impl Plugin for ConvoyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConvoyConfig>()
+            .init_resource::<ConvoyMission>()
+            .add_systems(
+                Startup,
+                reserve_route_space.run_if(resource_equals(GameMode::Convoy)),
+            )
+            .add_systems(
+                OnEnter(GameState::Gaming),
+                spawn_convoy.run_if(resource_equals(GameMode::Convoy)),
+            )
+            .add_systems(
+                Update,
+                (track_convoy, show_result_screen, draw_route)
+                    .chain()
+                    .run_if(in_state(GameState::Gaming))
+                    .run_if(resource_equals(GameMode::Convoy)),
+            );
+    }
+}
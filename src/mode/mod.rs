@@ -0,0 +1,69 @@
+pub mod control_points;
+pub mod convoy;
+
+use bevy::prelude::*;
+
+use crate::collision::{ENEMY_LAYER, PLAYER_LAYER};
+
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GameMode {
+    /// Free roaming fights, no objectives.
+    #[default]
+    Skirmish,
+    ControlPoints,
+    Convoy,
+}
+
+impl GameMode {
+    /// Pick the mode from a `--mode <name>` command line argument.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let name = args
+            .iter()
+            .position(|arg| arg == "--mode")
+            .and_then(|i| args.get(i + 1));
+
+        match name.map(|n| n.as_str()) {
+            Some("control-points") => GameMode::ControlPoints,
+            Some("convoy") => GameMode::Convoy,
+            Some("skirmish") | None => GameMode::Skirmish,
+            Some(other) => {
+                warn!("unknown game mode {}, falling back to skirmish", other);
+                GameMode::Skirmish
+            }
+        }
+    }
+}
+
+/// The two sides of a fight, the player and its allies against the enemies.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Side {
+    Player,
+    Enemy,
+}
+
+impl Side {
+    pub fn from_is_enemy(is_enemy: bool) -> Self {
+        if is_enemy {
+            Side::Enemy
+        } else {
+            Side::Player
+        }
+    }
+
+    pub fn collision_layer(&self) -> u32 {
+        match self {
+            Side::Player => PLAYER_LAYER,
+            Side::Enemy => ENEMY_LAYER,
+        }
+    }
+}
+
+pub struct GameModePlugin;
+
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameMode::from_args())
+            .add_plugins((control_points::ControlPointsPlugin, convoy::ConvoyPlugin));
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::{InputDevice, STICK_DEADZONE};
+
+const BINDINGS_PATH: &str = "bindings.ron";
+
+/// Everything the player can do. Gameplay systems read these through
+/// `ActionInput` instead of looking at raw keys or buttons.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum Action {
+    Throttle,
+    Steer,
+    Drift,
+    Dash,
+    Fire,
+    Zoom,
+    ToggleFullscreen,
+    ToggleFlowOverlay,
+}
+
+impl Action {
+    pub const ALL: [Action; 8] = [
+        Action::Throttle,
+        Action::Steer,
+        Action::Drift,
+        Action::Dash,
+        Action::Fire,
+        Action::Zoom,
+        Action::ToggleFullscreen,
+        Action::ToggleFlowOverlay,
+    ];
+
+    /// Whether the action has a negative direction (e.g. steering right).
+    pub fn is_axis(&self) -> bool {
+        matches!(self, Action::Throttle | Action::Steer | Action::Zoom)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    MouseWheel,
+    GamepadButton(GamepadButtonType),
+    GamepadAxis(GamepadAxisType),
+}
+
+impl Binding {
+    pub fn is_gamepad(&self) -> bool {
+        matches!(self, Binding::GamepadButton(_) | Binding::GamepadAxis(_))
+    }
+
+    fn value(&self, raw: &RawInput) -> f32 {
+        match *self {
+            Binding::Key(key) => raw.keys.pressed(key) as u8 as f32,
+            Binding::Mouse(button) => raw.mouse_buttons.pressed(button) as u8 as f32,
+            Binding::MouseWheel => raw.wheel,
+            Binding::GamepadButton(button_type) => match raw.gamepad {
+                Some(gamepad) => raw
+                    .gamepad_buttons
+                    .pressed(GamepadButton::new(gamepad, button_type))
+                    as u8 as f32,
+                None => 0.0,
+            },
+            Binding::GamepadAxis(axis_type) => {
+                let value = match raw.gamepad {
+                    Some(gamepad) => raw
+                        .axes
+                        .get(GamepadAxis::new(gamepad, axis_type))
+                        .unwrap_or(0.0),
+                    None => 0.0,
+                };
+                if value.abs() < STICK_DEADZONE {
+                    0.0
+                } else {
+                    value
+                }
+            }
+        }
+    }
+
+    fn just_pressed(&self, raw: &RawInput) -> bool {
+        match *self {
+            Binding::Key(key) => raw.keys.just_pressed(key),
+            Binding::Mouse(button) => raw.mouse_buttons.just_pressed(button),
+            Binding::MouseWheel => raw.wheel != 0.0,
+            Binding::GamepadButton(button_type) => match raw.gamepad {
+                Some(gamepad) => raw
+                    .gamepad_buttons
+                    .just_pressed(GamepadButton::new(gamepad, button_type)),
+                None => false,
+            },
+            Binding::GamepadAxis(_) => false,
+        }
+    }
+
+    /// The value of the binding for actions that happen in discrete steps,
+    /// like zooming. Held buttons only count on the frame they were pressed.
+    fn impulse(&self, raw: &RawInput) -> f32 {
+        match self {
+            Binding::MouseWheel => raw.wheel,
+            Binding::GamepadAxis(_) => 0.0,
+            _ => self.just_pressed(raw) as u8 as f32,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("{:?}", key),
+            Binding::Mouse(button) => format!("Mouse {:?}", button),
+            Binding::MouseWheel => "Mouse Wheel".to_string(),
+            Binding::GamepadButton(button_type) => format!("Pad {:?}", button_type),
+            Binding::GamepadAxis(axis_type) => format!("Pad {:?}", axis_type),
+        }
+    }
+}
+
+/// The bindings of a single action. Button actions only use `positive`,
+/// axis actions subtract the value of `negative` from it.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ActionBindings {
+    pub positive: Vec<Binding>,
+    pub negative: Vec<Binding>,
+}
+
+impl ActionBindings {
+    fn new(positive: Vec<Binding>, negative: Vec<Binding>) -> Self {
+        Self { positive, negative }
+    }
+
+    pub fn slot(&self, negative: bool) -> &Vec<Binding> {
+        if negative {
+            &self.negative
+        } else {
+            &self.positive
+        }
+    }
+
+    /// Replace the binding of the same device kind (keyboard and mouse or gamepad)
+    /// in the given slot, so rebinding one device keeps the other one intact.
+    pub fn rebind(&mut self, negative: bool, binding: Binding) {
+        if let Binding::GamepadAxis(_) = binding {
+            // A stick axis covers both directions of the action on its own.
+            self.positive.retain(|b| !b.is_gamepad());
+            self.negative.retain(|b| !b.is_gamepad());
+        }
+
+        let slot = if negative {
+            &mut self.negative
+        } else {
+            &mut self.positive
+        };
+        slot.retain(|b| b.is_gamepad() != binding.is_gamepad());
+        slot.push(binding);
+    }
+}
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+pub struct InputBindings(pub BTreeMap<Action, ActionBindings>);
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        let bindings = BTreeMap::from([
+            (
+                Action::Throttle,
+                ActionBindings::new(
+                    vec![
+                        Binding::Key(KeyCode::W),
+                        Binding::GamepadAxis(GamepadAxisType::LeftStickY),
+                    ],
+                    vec![Binding::Key(KeyCode::S)],
+                ),
+            ),
+            (
+                Action::Steer,
+                ActionBindings::new(
+                    vec![Binding::Key(KeyCode::A)],
+                    vec![
+                        Binding::Key(KeyCode::D),
+                        Binding::GamepadAxis(GamepadAxisType::LeftStickX),
+                    ],
+                ),
+            ),
+            (
+                Action::Drift,
+                ActionBindings::new(
+                    vec![
+                        Binding::Key(KeyCode::ShiftLeft),
+                        Binding::GamepadButton(GamepadButtonType::LeftTrigger2),
+                    ],
+                    vec![],
+                ),
+            ),
+            (
+                Action::Dash,
+                ActionBindings::new(
+                    vec![
+                        Binding::Key(KeyCode::Space),
+                        Binding::GamepadButton(GamepadButtonType::South),
+                    ],
+                    vec![],
+                ),
+            ),
+            (
+                Action::Fire,
+                ActionBindings::new(
+                    vec![
+                        Binding::Mouse(MouseButton::Left),
+                        Binding::GamepadButton(GamepadButtonType::RightTrigger2),
+                    ],
+                    vec![],
+                ),
+            ),
+            (
+                Action::Zoom,
+                ActionBindings::new(
+                    vec![
+                        Binding::MouseWheel,
+                        Binding::GamepadButton(GamepadButtonType::DPadRight),
+                    ],
+                    vec![Binding::GamepadButton(GamepadButtonType::DPadLeft)],
+                ),
+            ),
+            (
+                Action::ToggleFullscreen,
+                ActionBindings::new(
+                    vec![
+                        Binding::Key(KeyCode::B),
+                        Binding::GamepadButton(GamepadButtonType::DPadUp),
+                    ],
+                    vec![],
+                ),
+            ),
+            (
+                Action::ToggleFlowOverlay,
+                ActionBindings::new(vec![Binding::Key(KeyCode::F3)], vec![]),
+            ),
+        ]);
+        Self(bindings)
+    }
+}
+
+impl InputBindings {
+    /// Load the bindings from the config file, falling back to the defaults
+    /// for a missing or broken file and for actions the file doesn't mention.
+    pub fn load() -> Self {
+        let mut bindings = Self::default();
+        let content = match fs::read_to_string(BINDINGS_PATH) {
+            Ok(c) => c,
+            Err(_) => return bindings,
+        };
+
+        match ron::from_str::<InputBindings>(&content) {
+            Ok(loaded) => bindings.0.extend(loaded.0),
+            Err(err) => warn!(
+                "failed to parse {}, using default bindings, {}",
+                BINDINGS_PATH, err
+            ),
+        }
+        bindings
+    }
+
+    pub fn save(&self) {
+        let content = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(c) => c,
+            Err(err) => {
+                error!("failed to serialize bindings, {}", err);
+                return;
+            }
+        };
+
+        if let Err(err) = fs::write(BINDINGS_PATH, content) {
+            error!("failed to write {}, {}", BINDINGS_PATH, err);
+        }
+    }
+
+    pub fn get(&self, action: Action) -> ActionBindings {
+        self.0.get(&action).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct ActionState {
+    value: f32,
+    impulse: f32,
+    just_pressed: bool,
+}
+
+/// The state of every `Action` in the current frame.
+#[derive(Resource, Default)]
+pub struct ActionInput {
+    states: HashMap<Action, ActionState>,
+    disabled: bool,
+}
+
+impl ActionInput {
+    /// Analog value in `[-1, 1]`, digital bindings are either 0 or 1.
+    pub fn value(&self, action: Action) -> f32 {
+        self.states.get(&action).map_or(0.0, |s| s.value)
+    }
+
+    pub fn pressed(&self, action: Action) -> bool {
+        self.value(action) != 0.0
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.states.get(&action).is_some_and(|s| s.just_pressed)
+    }
+
+    /// Discrete steps of the action in this frame, e.g. the number of scroll lines.
+    pub fn impulse(&self, action: Action) -> f32 {
+        self.states.get(&action).map_or(0.0, |s| s.impulse)
+    }
+
+    /// Ignore all bindings until enabled again, used while menus are open.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
+struct RawInput<'a> {
+    keys: &'a Input<KeyCode>,
+    mouse_buttons: &'a Input<MouseButton>,
+    gamepad_buttons: &'a Input<GamepadButton>,
+    axes: &'a Axis<GamepadAxis>,
+    gamepad: Option<Gamepad>,
+    wheel: f32,
+}
+
+fn sum_bindings(bindings: &[Binding], f: impl Fn(&Binding) -> f32) -> f32 {
+    bindings.iter().map(f).sum()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_actions(
+    mut actions: ResMut<ActionInput>,
+    bindings: Res<InputBindings>,
+    device: Res<InputDevice>,
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    mut ev_scroll: EventReader<MouseWheel>,
+) {
+    let wheel: f32 = ev_scroll
+        .read()
+        .map(|ev| if ev.y > 0.0 { 1.0 } else { -1.0 })
+        .sum();
+
+    if actions.disabled {
+        actions.states.clear();
+        return;
+    }
+
+    let raw = RawInput {
+        keys: &keys,
+        mouse_buttons: &mouse_buttons,
+        gamepad_buttons: &gamepad_buttons,
+        axes: &axes,
+        gamepad: device.gamepad(),
+        wheel,
+    };
+
+    for (action, action_bindings) in &bindings.0 {
+        let value = sum_bindings(&action_bindings.positive, |b| b.value(&raw))
+            - sum_bindings(&action_bindings.negative, |b| b.value(&raw));
+        let impulse = sum_bindings(&action_bindings.positive, |b| b.impulse(&raw))
+            - sum_bindings(&action_bindings.negative, |b| b.impulse(&raw));
+        let just_pressed = action_bindings
+            .positive
+            .iter()
+            .chain(action_bindings.negative.iter())
+            .any(|b| b.just_pressed(&raw));
+
+        actions.states.insert(
+            *action,
+            ActionState {
+                value: value.clamp(-1.0, 1.0),
+                impulse,
+                just_pressed,
+            },
+        );
+    }
+}
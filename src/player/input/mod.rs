@@ -0,0 +1,202 @@
+pub mod action;
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, Window};
+
+use crate::vessel::ship::move_ships;
+use crate::world::camera::{CameraRig, CameraSettings};
+use crate::world::MainCamera;
+use crate::GameState;
+
+use super::Player;
+use action::{update_actions, Action, ActionInput, InputBindings};
+
+const STICK_DEADZONE: f32 = 0.15;
+const GAMEPAD_AIM_DISTANCE: f32 = 300.0;
+
+/// The device the player used last. Gameplay systems use this to decide
+/// whether the aim point comes from the mouse cursor or the right stick.
+#[derive(Resource, Default, Clone, Copy, PartialEq)]
+pub enum InputDevice {
+    #[default]
+    KeyboardMouse,
+    Gamepad(Gamepad),
+}
+
+impl InputDevice {
+    pub fn gamepad(&self) -> Option<Gamepad> {
+        match self {
+            InputDevice::KeyboardMouse => None,
+            InputDevice::Gamepad(gamepad) => Some(*gamepad),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct MouseWorldCoords(pub Vec2);
+
+/// The point in world space the player is aiming at,
+/// either the mouse cursor or a point relative to the right stick.
+#[derive(Resource, Default)]
+pub struct AimWorldCoords(pub Vec2);
+
+fn stick(
+    axes: &Axis<GamepadAxis>,
+    gamepad: Gamepad,
+    x: GamepadAxisType,
+    y: GamepadAxisType,
+) -> Vec2 {
+    let direction = Vec2::new(
+        axes.get(GamepadAxis::new(gamepad, x)).unwrap_or(0.0),
+        axes.get(GamepadAxis::new(gamepad, y)).unwrap_or(0.0),
+    );
+
+    if direction.length() < STICK_DEADZONE {
+        Vec2::ZERO
+    } else {
+        direction.clamp_length_max(1.0)
+    }
+}
+
+pub fn left_stick(axes: &Axis<GamepadAxis>, gamepad: Gamepad) -> Vec2 {
+    stick(
+        axes,
+        gamepad,
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+    )
+}
+
+pub fn right_stick(axes: &Axis<GamepadAxis>, gamepad: Gamepad) -> Vec2 {
+    stick(
+        axes,
+        gamepad,
+        GamepadAxisType::RightStickX,
+        GamepadAxisType::RightStickY,
+    )
+}
+
+fn detect_input_device(
+    mut device: ResMut<InputDevice>,
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut ev_cursor_moved: EventReader<CursorMoved>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+) {
+    let mut new_device = *device;
+
+    if keys.get_just_pressed().next().is_some()
+        || mouse_buttons.get_just_pressed().next().is_some()
+        || ev_cursor_moved.read().last().is_some()
+    {
+        new_device = InputDevice::KeyboardMouse;
+    }
+
+    for gamepad in gamepads.iter() {
+        let button_pressed = gamepad_buttons
+            .get_just_pressed()
+            .any(|button| button.gamepad == gamepad);
+        let stick_moved =
+            left_stick(&axes, gamepad) != Vec2::ZERO || right_stick(&axes, gamepad) != Vec2::ZERO;
+
+        if button_pressed || stick_moved {
+            new_device = InputDevice::Gamepad(gamepad);
+        }
+    }
+
+    if *device != new_device {
+        *device = new_device;
+    }
+}
+
+pub fn fetch_mouse_world_coords(
+    mut mouse_coords: ResMut<MouseWorldCoords>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+) {
+    let (camera, camera_transform) = q_camera.single();
+    let window = q_window.single();
+
+    if let Some(world_position) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+        .map(|ray| ray.origin.truncate())
+    {
+        mouse_coords.0 = world_position;
+    }
+}
+
+pub fn fetch_aim_world_coords(
+    mut aim_coords: ResMut<AimWorldCoords>,
+    mut last_stick_direction: Local<Vec2>,
+    device: Res<InputDevice>,
+    mouse_coords: Res<MouseWorldCoords>,
+    axes: Res<Axis<GamepadAxis>>,
+    q_player: Query<&Transform, With<Player>>,
+    q_projection: Query<&OrthographicProjection, With<MainCamera>>,
+) {
+    let gamepad = match device.gamepad() {
+        Some(g) => g,
+        None => {
+            aim_coords.0 = mouse_coords.0;
+            return;
+        }
+    };
+
+    let player_transform = match q_player.get_single() {
+        Ok(p) => p,
+        Err(_) => return,
+    };
+
+    let direction = right_stick(&axes, gamepad);
+    if direction != Vec2::ZERO {
+        *last_stick_direction = direction;
+    }
+    if *last_stick_direction == Vec2::ZERO {
+        *last_stick_direction = player_transform.local_y().truncate();
+    }
+
+    let scale = q_projection.single().scale;
+    aim_coords.0 = player_transform.translation.truncate()
+        + *last_stick_direction * GAMEPAD_AIM_DISTANCE * scale;
+}
+
+fn zoom_camera(
+    actions: Res<ActionInput>,
+    settings: Res<CameraSettings>,
+    mut q_rig: Query<&mut CameraRig, With<MainCamera>>,
+) {
+    let zoom = actions.impulse(Action::Zoom);
+    if zoom == 0.0 {
+        return;
+    }
+
+    let mut rig = q_rig.single_mut();
+    rig.zoom(&settings, zoom);
+}
+
+pub struct GuardianInputPlugin;
+
+impl Plugin for GuardianInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (detect_input_device, update_actions).chain())
+            .add_systems(
+                Update,
+                (
+                    zoom_camera,
+                    fetch_mouse_world_coords,
+                    fetch_aim_world_coords.after(move_ships),
+                )
+                    .chain()
+                    .after(update_actions)
+                    .run_if(in_state(GameState::Gaming)),
+            )
+            .insert_resource(InputBindings::load())
+            .init_resource::<ActionInput>()
+            .init_resource::<InputDevice>()
+            .init_resource::<MouseWorldCoords>()
+            .init_resource::<AimWorldCoords>();
+    }
+}
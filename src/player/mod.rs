@@ -3,9 +3,10 @@ pub mod input;
 use bevy::prelude::*;
 
 use crate::collision::{PLAYER_LAYER, PROJECTILE_LAYER};
+use crate::player::input::action::{update_actions, Action, ActionInput};
 use crate::turret::TurretType;
 use crate::ui::health::Health;
-use crate::vessel::ship::BigShip;
+use crate::vessel::ship::{update_dash, BigShip, DashEnergy};
 use crate::vessel::SpawnVessel;
 use crate::{GameAssets, GameState, ShipStats};
 
@@ -17,7 +18,13 @@ impl Plugin for GuardianPlayerPlugin {
             .add_plugins((input::GuardianInputPlugin,))
             .add_systems(
                 Update,
-                (steer_player, accelerate_player, toggle_drift, toggle_dash)
+                (
+                    steer_player,
+                    accelerate_player,
+                    toggle_drift,
+                    toggle_dash.before(update_dash),
+                )
+                    .after(update_actions)
                     .run_if(in_state(GameState::Gaming)),
             );
     }
@@ -52,24 +59,17 @@ fn spawn_player_big(
     });
 }
 
-fn steer_player(keys: Res<Input<KeyCode>>, mut q_player: Query<&mut ShipStats, With<Player>>) {
+fn steer_player(actions: Res<ActionInput>, mut q_player: Query<&mut ShipStats, With<Player>>) {
     let mut ship_stats = match q_player.get_single_mut() {
         Ok(s) => s,
         Err(_) => return,
     };
 
-    let mut steer_direction = 0.0;
-    if keys.pressed(KeyCode::A) {
-        steer_direction += 1.0;
-    }
-    if keys.pressed(KeyCode::D) {
-        steer_direction -= 1.0;
-    }
-    ship_stats.current_steering_direction = steer_direction;
+    ship_stats.current_steering_direction = actions.value(Action::Steer);
 }
 
 fn accelerate_player(
-    keys: Res<Input<KeyCode>>,
+    actions: Res<ActionInput>,
     time: Res<Time>,
     mut q_player: Query<(&Transform, &mut ShipStats), With<Player>>,
 ) {
@@ -78,13 +78,7 @@ fn accelerate_player(
         Err(_) => return,
     };
 
-    let mut acceleration: f32 = 0.0;
-    if keys.pressed(KeyCode::W) {
-        acceleration += 1.0;
-    }
-    if keys.pressed(KeyCode::S) {
-        acceleration -= 1.0;
-    }
+    let acceleration = actions.value(Action::Throttle);
 
     ship_stats.drag = (-acceleration.abs() + 1.0) * 2.0;
     let speed = ship_stats.delta_speed;
@@ -92,24 +86,24 @@ fn accelerate_player(
         transform.local_y().truncate() * speed * acceleration * time.delta_seconds();
 }
 
-fn toggle_drift(keys: Res<Input<KeyCode>>, mut q_player: Query<&mut ShipStats, With<Player>>) {
+fn toggle_drift(actions: Res<ActionInput>, mut q_player: Query<&mut ShipStats, With<Player>>) {
     let mut ship_stats = match q_player.get_single_mut() {
         Ok(p) => p,
         Err(_) => return,
     };
 
-    if keys.pressed(KeyCode::ShiftLeft) {
+    if actions.pressed(Action::Drift) {
         ship_stats.traction = 0.0;
     } else {
         ship_stats.traction = 5.0;
     }
 }
 
-fn toggle_dash(keys: Res<Input<KeyCode>>, mut q_player: Query<&mut ShipStats, With<Player>>) {
-    let mut ship_stats = match q_player.get_single_mut() {
+fn toggle_dash(actions: Res<ActionInput>, mut q_player: Query<&mut DashEnergy, With<Player>>) {
+    let mut dash_energy = match q_player.get_single_mut() {
         Ok(p) => p,
         Err(_) => return,
     };
 
-    ship_stats.dash = keys.pressed(KeyCode::Space);
+    dash_energy.requested = actions.pressed(Action::Dash);
 }
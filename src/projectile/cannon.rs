@@ -53,6 +53,7 @@ fn spawn_cannon(commands: &mut Commands, assets: &Res<GameAssets>, ev: &TurretTr
         Projectile::new(
             ProjectileType::Cannon,
             ev.source,
+            PROJECTILE_LAYER,
             ev.turret_mask,
             DAMAGE * ev.stats_scale,
         ),
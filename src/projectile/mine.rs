@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    collision::PROJECTILE_LAYER,
+    turret::{TurretTriggered, TurretType},
+    vessel::section::{HullSection, HullSections},
+    GameAssets, GameState,
+};
+
+use super::{check_projectile_intersections, Projectile, ProjectileCollision, ProjectileType};
+
+const DAMAGE: f32 = 60.0;
+/// Seconds until an untouched mine goes off on its own.
+const LIFE_TIME: f32 = 45.0;
+/// Seconds after dropping before a mine reacts to anything.
+const ARM_TIME: f32 = 1.5;
+const TRIGGER_RADIUS: f32 = 120.0;
+const BLAST_RADIUS: f32 = 250.0;
+/// Damage a mine takes from gunfire before it goes off.
+const HIT_POINTS: f32 = 3.0;
+const MAX_ACTIVE_MINES: usize = 6;
+/// Distance behind the layer a mine is dropped at.
+const DROP_DISTANCE: f32 = 40.0;
+const MINE_SIZE: f32 = 2.0;
+const ARMED_BLINK_RATE: f32 = 4.0;
+const ARMED_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
+
+/// Stationary projectile that explodes when a hostile comes close
+/// instead of on contact like every other projectile.
+#[derive(Component, Clone)]
+pub struct Mine {
+    arm_timer: Timer,
+    life_timer: Timer,
+    /// Collision layers that set the mine off.
+    trigger_mask: u32,
+    hit_points: f32,
+    triggered: bool,
+}
+
+impl Mine {
+    fn new(trigger_mask: u32) -> Self {
+        Self {
+            arm_timer: Timer::from_seconds(ARM_TIME, TimerMode::Once),
+            life_timer: Timer::from_seconds(LIFE_TIME, TimerMode::Once),
+            trigger_mask,
+            hit_points: HIT_POINTS,
+            triggered: false,
+        }
+    }
+
+    fn filter(&self, entity: Entity) -> QueryFilter<'_> {
+        QueryFilter {
+            groups: Some(CollisionGroups::new(
+                Group::from_bits(PROJECTILE_LAYER).unwrap(),
+                Group::from_bits(self.trigger_mask).unwrap(),
+            )),
+            exclude_collider: Some(entity),
+            ..default()
+        }
+    }
+}
+
+fn spawn_mines(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    q_mines: Query<&Projectile, With<Mine>>,
+    q_sources: Query<&Transform, Without<Mine>>,
+    mut ev_turret_triggered: EventReader<TurretTriggered>,
+) {
+    for ev in ev_turret_triggered.read() {
+        if ev.turret_type != TurretType::MineLayer {
+            continue;
+        }
+
+        let active = q_mines
+            .iter()
+            .filter(|projectile| projectile.source == ev.source)
+            .count();
+        if active >= MAX_ACTIVE_MINES {
+            continue;
+        }
+
+        // Behind the vessel's heading, so slow or idle layers don't drop onto their turret.
+        let heading = match q_sources.get(ev.source) {
+            Ok(t) => t.local_y(),
+            Err(_) => continue,
+        };
+        let behind = -heading.truncate() * DROP_DISTANCE;
+        let transform =
+            Transform::from_translation(ev.source_transform.translation + behind.extend(0.0))
+                .with_scale(Vec3::splat(MINE_SIZE));
+
+        commands.spawn((
+            Mine::new(ev.turret_mask),
+            Projectile::new(
+                ProjectileType::Mine,
+                ev.source,
+                PROJECTILE_LAYER,
+                ev.turret_mask,
+                DAMAGE * ev.stats_scale,
+            ),
+            SpriteBundle {
+                transform,
+                texture: assets.mine.clone(),
+                ..default()
+            },
+            Collider::ball(6.0),
+            // Mines sit on the layer of their side so hostile gunfire can shoot them.
+            CollisionGroups::new(
+                Group::from_bits(ev.turret_layer).unwrap(),
+                Group::from_bits(PROJECTILE_LAYER).unwrap(),
+            ),
+        ));
+    }
+}
+
+/// Expired mines detonate instead of vanishing, so their explosion is a real one.
+fn arm_mines(time: Res<Time>, mut q_mines: Query<(&mut Mine, &mut Sprite)>) {
+    for (mut mine, mut sprite) in &mut q_mines {
+        mine.life_timer.tick(time.delta());
+        mine.triggered |= mine.life_timer.finished();
+
+        mine.arm_timer.tick(time.delta());
+        if !mine.arm_timer.finished() {
+            continue;
+        }
+
+        let blink = (time.elapsed_seconds() * ARMED_BLINK_RATE).sin() > 0.0;
+        sprite.color = if blink { ARMED_COLOR } else { Color::WHITE };
+    }
+}
+
+fn trigger_mines(
+    rapier_context: Res<RapierContext>,
+    mut q_mines: Query<(Entity, &Transform, &mut Mine)>,
+    q_projectiles: Query<(), With<Projectile>>,
+) {
+    for (entity, transform, mut mine) in &mut q_mines {
+        if mine.triggered || !mine.arm_timer.finished() {
+            continue;
+        }
+
+        let mut hostile_nearby = false;
+        rapier_context.intersections_with_shape(
+            transform.translation.truncate(),
+            0.0,
+            &Collider::ball(TRIGGER_RADIUS),
+            mine.filter(entity),
+            |other| {
+                // Other mines don't set each other off, only their blasts do.
+                if q_projectiles.get(other).is_ok() {
+                    return true;
+                }
+                hostile_nearby = true;
+                false
+            },
+        );
+        mine.triggered = hostile_nearby;
+    }
+}
+
+/// Mines can be shot, they go off once they took enough damage.
+fn damage_mines(
+    mut q_mines: Query<&mut Mine>,
+    mut ev_projectile_collision: EventReader<ProjectileCollision>,
+) {
+    for ev in ev_projectile_collision.read() {
+        if let Ok(mut mine) = q_mines.get_mut(ev.target) {
+            mine.hit_points -= ev.projectile.damage;
+            mine.triggered |= mine.hit_points <= 0.0;
+        }
+    }
+}
+
+/// Damage everything hostile in the blast radius, sectioned hulls only once per vessel.
+fn explode_mines(
+    rapier_context: Res<RapierContext>,
+    mut q_mines: Query<(Entity, &Transform, &Mine, &mut Projectile)>,
+    q_sections: Query<&HullSection>,
+    q_sectioned: Query<(), With<HullSections>>,
+    mut ev_projectile_collision: EventWriter<ProjectileCollision>,
+) {
+    for (entity, transform, mine, mut projectile) in &mut q_mines {
+        if !mine.triggered || projectile.disabled {
+            continue;
+        }
+        projectile.disabled = true;
+
+        let mut hit_vessels = Vec::new();
+        rapier_context.intersections_with_shape(
+            transform.translation.truncate(),
+            0.0,
+            &Collider::ball(BLAST_RADIUS),
+            mine.filter(entity),
+            |other| {
+                if q_sectioned.get(other).is_ok() {
+                    return true;
+                }
+                if let Ok(section) = q_sections.get(other) {
+                    if hit_vessels.contains(&section.vessel) {
+                        return true;
+                    }
+                    hit_vessels.push(section.vessel);
+                }
+
+                ev_projectile_collision.send(ProjectileCollision {
+                    projectile: projectile.clone(),
+                    target: other,
+                });
+                true
+            },
+        );
+    }
+}
+
+pub struct MinePlugin;
+
+impl Plugin for MinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_mines,
+                arm_mines,
+                trigger_mines,
+                damage_mines.after(check_projectile_intersections),
+                explode_mines,
+            )
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -1,13 +1,15 @@
 mod cannon;
+mod mine;
 pub mod rocket;
 mod rocket_explosion;
+pub mod torpedo;
 
 use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::{collision::PROJECTILE_LAYER, GameState};
+use crate::{collision::TERRAIN_LAYER, vessel::section::HullSections, GameState};
 
 pub struct ProjectilePlugin;
 
@@ -15,8 +17,10 @@ impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             cannon::CannonPlugin,
+            mine::MinePlugin,
             rocket::RocketPlugin,
             rocket_explosion::RocketExplosionPlugin,
+            torpedo::TorpedoPlugin,
         ))
         .add_event::<ProjectileCollision>()
         .add_event::<ProjectileDespawn>()
@@ -36,6 +40,8 @@ impl Plugin for ProjectilePlugin {
 pub enum ProjectileType {
     Cannon,
     Rocket,
+    Mine,
+    Torpedo,
 }
 
 #[derive(Component, Clone)]
@@ -51,15 +57,17 @@ impl Projectile {
     pub fn new(
         projectile_type: ProjectileType,
         source: Entity,
+        collision_layer: u32,
         collision_mask: u32,
         damage: f32,
     ) -> Self {
         Self {
             projectile_type,
             source,
+            // Terrain absorbs every projectile, no matter who fired it.
             groups: CollisionGroups::new(
-                Group::from_bits(PROJECTILE_LAYER).unwrap(),
-                Group::from_bits(collision_mask).unwrap(),
+                Group::from_bits(collision_layer).unwrap(),
+                Group::from_bits(collision_mask | TERRAIN_LAYER).unwrap(),
             ),
             damage,
             disabled: false,
@@ -67,6 +75,10 @@ impl Projectile {
     }
 }
 
+/// Marks projectiles slow enough to be carried by wind and currents.
+#[derive(Component, Clone)]
+pub struct DriftsWithFlow;
+
 #[derive(Component, Clone)]
 pub struct ProjectileTimer {
     pub timer: Timer,
@@ -108,9 +120,12 @@ fn despawn_projectiles(
     }
 }
 
+/// Vessels split into hull sections are hit through their sections only.
+/// Mines go off on proximity instead, see `mine::trigger_mines`.
 fn check_projectile_intersections(
     rapier_context: Res<RapierContext>,
-    mut q_projectiles: Query<(Entity, &Transform, &mut Projectile, &Collider)>,
+    mut q_projectiles: Query<(Entity, &Transform, &mut Projectile, &Collider), Without<mine::Mine>>,
+    q_sectioned: Query<(), With<HullSections>>,
     mut ev_projectile_collision: EventWriter<ProjectileCollision>,
 ) {
     for (entity, transform, mut projectile, collider) in &mut q_projectiles {
@@ -133,6 +148,9 @@ fn check_projectile_intersections(
                 if projectile.source == other {
                     return false;
                 }
+                if q_sectioned.get(other).is_ok() {
+                    return true;
+                }
                 ev_projectile_collision.send(ProjectileCollision {
                     projectile: projectile.clone(),
                     target: other,
@@ -9,7 +9,7 @@ use crate::{
     GameAssets, GameState,
 };
 
-use super::{Projectile, ProjectileTimer, ProjectileType};
+use super::{DriftsWithFlow, Projectile, ProjectileTimer, ProjectileType};
 
 const LEFT_TURRET_OFFSET: Vec3 = Vec3::new(5.0, 5.0, 0.0);
 const RIGHT_TURRET_OFFSET: Vec3 = Vec3::new(-5.0, 5.0, 0.0);
@@ -58,6 +58,7 @@ fn spawn_rocket(commands: &mut Commands, assets: &Res<GameAssets>, ev: &TurretTr
     let projectile = Projectile::new(
         ProjectileType::Rocket,
         ev.source,
+        PROJECTILE_LAYER,
         ev.turret_mask,
         DAMAGE * ev.stats_scale,
     );
@@ -72,6 +73,7 @@ fn spawn_rocket(commands: &mut Commands, assets: &Res<GameAssets>, ev: &TurretTr
         rocket.clone(),
         projectile.clone(),
         projectile_timer.clone(),
+        DriftsWithFlow,
         SpriteBundle {
             transform: left_transform,
             texture: assets.rocket.clone(),
@@ -85,6 +87,7 @@ fn spawn_rocket(commands: &mut Commands, assets: &Res<GameAssets>, ev: &TurretTr
         rocket.clone(),
         projectile.clone(),
         projectile_timer.clone(),
+        DriftsWithFlow,
         SpriteBundle {
             transform: right_transform,
             texture: assets.rocket.clone(),
@@ -112,6 +115,7 @@ fn spawn_medium_rocket(
     let projectile = Projectile::new(
         ProjectileType::Rocket,
         ev.source,
+        PROJECTILE_LAYER,
         ev.turret_mask,
         MEDIUM_DAMAGE * ev.stats_scale,
     );
@@ -126,6 +130,7 @@ fn spawn_medium_rocket(
         rocket.clone(),
         projectile.clone(),
         projectile_timer.clone(),
+        DriftsWithFlow,
         SpriteBundle {
             transform,
             texture: assets.medium_rocket.clone(),
@@ -8,6 +8,8 @@ use crate::{
 use super::{ProjectileDespawn, ProjectileType};
 
 const EXPLOSION_SIZE: f32 = 4.0;
+const MINE_EXPLOSION_SIZE: f32 = 10.0;
+const TORPEDO_EXPLOSION_SIZE: f32 = 7.0;
 
 fn spawn_rocket_explosion(
     mut commands: Commands,
@@ -15,12 +17,17 @@ fn spawn_rocket_explosion(
     mut ev_projectile_despawn: EventReader<ProjectileDespawn>,
 ) {
     for ev in ev_projectile_despawn.read() {
-        if ev.projectile.projectile_type != ProjectileType::Rocket {
-            continue;
-        }
+        let size = match ev.projectile.projectile_type {
+            ProjectileType::Rocket => EXPLOSION_SIZE,
+            ProjectileType::Mine => MINE_EXPLOSION_SIZE,
+            ProjectileType::Torpedo => TORPEDO_EXPLOSION_SIZE,
+            _ => continue,
+        };
 
-        let transform =
-            Transform::from_translation(ev.position).with_scale(Vec3::splat(EXPLOSION_SIZE));
+        // Torpedoes run submerged but blow up at the surface.
+        let mut position = ev.position;
+        position.z = position.z.max(0.0);
+        let transform = Transform::from_translation(position).with_scale(Vec3::splat(size));
         commands.spawn((
             AnimSprite::new(8, false),
             AnimSpriteTimer::default(),
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    collision::TORPEDO_LAYER,
+    turret::{TurretTriggered, TurretType},
+    GameState,
+};
+
+use super::{Projectile, ProjectileTimer, ProjectileType};
+
+const DAMAGE: f32 = 150.0;
+const LIFE_TIME: f32 = 8.0;
+const SPEED: f32 = 280.0;
+/// Below the terrain, torpedoes run submerged.
+const TORPEDO_Z: f32 = -60.0;
+const TORPEDO_SIZE: Vec2 = Vec2::new(6.0, 26.0);
+const TORPEDO_COLOR: Color = Color::rgba(0.05, 0.1, 0.15, 0.35);
+
+const WAKE_INTERVAL: f32 = 0.05;
+const WAKE_LIFE_TIME: f32 = 1.8;
+const WAKE_START_SIZE: f32 = 6.0;
+const WAKE_END_SIZE: f32 = 22.0;
+const WAKE_COLOR: Color = Color::rgba(0.85, 0.95, 1.0, 0.45);
+
+#[derive(Component, Clone)]
+pub struct Torpedo {
+    wake_timer: Timer,
+}
+
+impl Default for Torpedo {
+    fn default() -> Self {
+        Self {
+            wake_timer: Timer::from_seconds(WAKE_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Foam left on the surface above a torpedo, the only thing that gives it away.
+#[derive(Component)]
+struct TorpedoWake {
+    timer: Timer,
+}
+
+fn spawn_torpedo(commands: &mut Commands, ev: &TurretTriggered) {
+    let mut transform = Transform::from_translation(ev.source_transform.translation)
+        .with_rotation(ev.source_transform.rotation);
+    transform.translation.z = TORPEDO_Z;
+
+    commands.spawn((
+        Torpedo::default(),
+        Projectile::new(
+            ProjectileType::Torpedo,
+            ev.source,
+            TORPEDO_LAYER,
+            ev.turret_mask,
+            DAMAGE * ev.stats_scale,
+        ),
+        ProjectileTimer::new(LIFE_TIME),
+        SpriteBundle {
+            transform,
+            sprite: Sprite {
+                color: TORPEDO_COLOR,
+                custom_size: Some(TORPEDO_SIZE),
+                ..default()
+            },
+            ..default()
+        },
+        Collider::capsule(Vec2::new(0.0, -8.0), Vec2::new(0.0, 8.0), 3.0),
+        // Only vessels collide with the torpedo layer, so torpedoes can't be shot down
+        // and pass below terrain and turrets.
+        CollisionGroups::new(
+            Group::from_bits(TORPEDO_LAYER).unwrap(),
+            Group::from_bits(ev.turret_mask).unwrap(),
+        ),
+    ));
+}
+
+fn spawn_torpedoes(mut commands: Commands, mut ev_turret_triggered: EventReader<TurretTriggered>) {
+    for ev in ev_turret_triggered.read() {
+        if ev.turret_type == TurretType::Torpedo {
+            spawn_torpedo(&mut commands, ev);
+        }
+    }
+}
+
+fn move_torpedoes(time: Res<Time>, mut q_torpedoes: Query<&mut Transform, With<Torpedo>>) {
+    for mut transform in &mut q_torpedoes {
+        let direction = transform.local_y();
+        transform.translation += direction * SPEED * time.delta_seconds();
+    }
+}
+
+fn spawn_wakes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_torpedoes: Query<(&Transform, &mut Torpedo)>,
+) {
+    for (transform, mut torpedo) in &mut q_torpedoes {
+        torpedo.wake_timer.tick(time.delta());
+        if !torpedo.wake_timer.just_finished() {
+            continue;
+        }
+
+        commands.spawn((
+            TorpedoWake {
+                timer: Timer::from_seconds(WAKE_LIFE_TIME, TimerMode::Once),
+            },
+            SpriteBundle {
+                transform: Transform::from_translation(transform.translation + Vec3::Z)
+                    .with_scale(Vec3::splat(WAKE_START_SIZE)),
+                sprite: Sprite {
+                    color: WAKE_COLOR,
+                    custom_size: Some(Vec2::ONE),
+                    ..default()
+                },
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Wake foam spreads out and fades away.
+fn fade_wakes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_wakes: Query<(Entity, &mut Transform, &mut Sprite, &mut TorpedoWake)>,
+) {
+    for (entity, mut transform, mut sprite, mut wake) in &mut q_wakes {
+        wake.timer.tick(time.delta());
+        if wake.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let t = wake.timer.percent();
+        transform.scale = Vec3::splat(WAKE_START_SIZE + (WAKE_END_SIZE - WAKE_START_SIZE) * t);
+        sprite.color.set_a(WAKE_COLOR.a() * (1.0 - t));
+    }
+}
+
+pub struct TorpedoPlugin;
+
+impl Plugin for TorpedoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_torpedoes, move_torpedoes, spawn_wakes, fade_wakes)
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    collision::{PROJECTILE_LAYER, TERRAIN_LAYER},
+    projectile::Projectile,
+    ui::health::DealDamage,
+    vessel::section::HullSections,
+    world::weather::WeatherEffects,
+    GameState,
+};
+
+use super::{
+    range_from_turret_type, rotate_turrets, Turret, TurretTriggered, TurretType, TurretWrecked,
+};
+
+const DAMAGE_PER_SECOND: f32 = 40.0;
+/// Distance from the center of the turret to the tip of the emitter.
+const BARREL_LENGTH: f32 = 14.0;
+/// Seconds a beam keeps firing after it got triggered, a bit longer than its cooldown.
+const HOLD_TIME: f32 = 0.12;
+/// Heat gained per second while firing, the beam overheats at 1.
+const HEAT_RATE: f32 = 0.25;
+const COOL_RATE: f32 = 0.4;
+/// An overheated beam can fire again once it cooled down to this.
+const RESUME_HEAT: f32 = 0.3;
+const BEAM_WIDTH: f32 = 4.0;
+const BEAM_Z: f32 = -1.0;
+const COOL_COLOR: [f32; 4] = [0.5, 0.9, 1.0, 0.85];
+const HOT_COLOR: [f32; 4] = [1.0, 0.45, 0.3, 0.85];
+
+/// Turret that fires a continuous beam instead of projectiles.
+#[derive(Component)]
+pub struct BeamEmitter {
+    pub heat: f32,
+    pub overheated: bool,
+    /// Seconds left until the beam stops without a new trigger.
+    hold: f32,
+    /// Collision layers the beam damages.
+    mask: u32,
+    beam: Entity,
+}
+
+impl BeamEmitter {
+    fn firing(&self) -> bool {
+        self.hold > 0.0 && !self.overheated
+    }
+
+    fn update_heat(&mut self, firing: bool, dt: f32) {
+        if firing {
+            self.heat = (self.heat + HEAT_RATE * dt).min(1.0);
+            self.overheated = self.heat >= 1.0;
+            return;
+        }
+
+        self.heat = (self.heat - COOL_RATE * dt).max(0.0);
+        if self.overheated && self.heat <= RESUME_HEAT {
+            self.overheated = false;
+        }
+    }
+}
+
+fn attach_beam_emitters(
+    mut commands: Commands,
+    q_turrets: Query<(Entity, &Turret), Added<Turret>>,
+) {
+    for (entity, turret) in &q_turrets {
+        if turret.turret_type != TurretType::Beam {
+            continue;
+        }
+
+        let beam = commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::ONE),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            })
+            .id();
+        commands
+            .entity(entity)
+            .insert(BeamEmitter {
+                heat: 0.0,
+                overheated: false,
+                hold: 0.0,
+                mask: 0,
+                beam,
+            })
+            .add_child(beam);
+    }
+}
+
+fn hold_beams(
+    mut q_emitters: Query<&mut BeamEmitter>,
+    mut ev_turret_triggered: EventReader<TurretTriggered>,
+) {
+    for ev in ev_turret_triggered.read() {
+        if ev.turret_type != TurretType::Beam {
+            continue;
+        }
+        if let Ok(mut emitter) = q_emitters.get_mut(ev.turret) {
+            emitter.hold = HOLD_TIME;
+            emitter.mask = ev.turret_mask;
+        }
+    }
+}
+
+/// Ray cast from the emitter and damage the first hostile in the way,
+/// terrain blocks the beam.
+#[allow(clippy::type_complexity)]
+fn fire_beams(
+    time: Res<Time>,
+    rapier_context: Res<RapierContext>,
+    effects: Res<WeatherEffects>,
+    mut q_emitters: Query<(&Turret, &Transform, &mut BeamEmitter, Has<TurretWrecked>)>,
+    mut q_beams: Query<(&mut Transform, &mut Sprite, &mut Visibility), Without<BeamEmitter>>,
+    q_ignored: Query<(), Or<(With<Projectile>, With<HullSections>)>>,
+    mut ev_deal_damage: EventWriter<DealDamage>,
+) {
+    let dt = time.delta_seconds();
+    for (turret, transform, mut emitter, wrecked) in &mut q_emitters {
+        emitter.hold -= dt;
+        let firing = emitter.firing() && !wrecked;
+        emitter.update_heat(firing, dt);
+
+        let (mut beam_transform, mut sprite, mut visibility) = match q_beams.get_mut(emitter.beam) {
+            Ok(b) => b,
+            Err(_) => continue,
+        };
+        if !firing {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        let dir = transform.local_y().truncate();
+        let origin = transform.translation.truncate() + dir * BARREL_LENGTH;
+        let range = range_from_turret_type(TurretType::Beam) * effects.turret_range_factor;
+        let predicate = |entity| q_ignored.get(entity).is_err();
+        let filter = QueryFilter {
+            groups: Some(CollisionGroups::new(
+                Group::from_bits(PROJECTILE_LAYER).unwrap(),
+                Group::from_bits(emitter.mask | TERRAIN_LAYER).unwrap(),
+            )),
+            predicate: Some(&predicate),
+            ..default()
+        };
+
+        let length = match rapier_context.cast_ray(origin, dir, range, true, filter) {
+            Some((target, distance)) => {
+                ev_deal_damage.send(DealDamage {
+                    target,
+                    amount: DAMAGE_PER_SECOND * turret.stats_scale * dt,
+                });
+                distance
+            }
+            None => range,
+        };
+
+        let flicker = 1.0 + 0.3 * (time.elapsed_seconds() * 40.0).sin();
+        beam_transform.translation = Vec3::new(0.0, BARREL_LENGTH + length / 2.0, BEAM_Z);
+        beam_transform.scale = Vec3::new(BEAM_WIDTH * flicker, length, 1.0);
+        let [r, g, b, a] =
+            std::array::from_fn(|i| COOL_COLOR[i] + (HOT_COLOR[i] - COOL_COLOR[i]) * emitter.heat);
+        sprite.color = Color::rgba(r, g, b, a);
+        *visibility = Visibility::Inherited;
+    }
+}
+
+pub struct BeamPlugin;
+
+impl Plugin for BeamPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                attach_beam_emitters,
+                hold_beams,
+                fire_beams.after(rotate_turrets),
+            )
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
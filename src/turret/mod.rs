@@ -1,14 +1,22 @@
+pub mod beam;
+
 use std::time::Duration;
 
 use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Collider, CollisionGroups, Group};
 
-use crate::collision::{ENEMY_LAYER, PLAYER_LAYER};
-use crate::enemy::Enemy;
-use crate::player::input::{fetch_mouse_world_coords, MouseWorldCoords};
+use crate::collision::{ENEMY_LAYER, PLAYER_LAYER, TORPEDO_LAYER};
+use crate::enemy::{Enemy, HighValueTarget};
+use crate::player::input::action::{update_actions, Action, ActionInput};
+use crate::player::input::{fetch_aim_world_coords, AimWorldCoords};
 use crate::player::Player;
+use crate::ui::health::Health;
 use crate::utils::quat_from_vec2;
+use crate::vessel::sensor::{update_detection, Detected};
 use crate::vessel::ship::{move_ships, steer_ships};
+use crate::vessel::station::rotate_stations;
 use crate::vessel::SpawnVessel;
+use crate::world::weather::WeatherEffects;
 use crate::{GameAssets, GameState, ShipStats};
 
 const TURRET_Z_OFFSET: Vec3 = Vec3::new(0.0, 0.0, 10.0);
@@ -17,29 +25,36 @@ pub struct TurretPlugin;
 
 impl Plugin for TurretPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                reposition_turrets.after(move_ships).after(steer_ships),
-                update_player_turret_targets,
-                update_enemy_turret_targets,
-                rotate_turrets.after(fetch_mouse_world_coords),
-            )
-                .chain()
-                .run_if(in_state(GameState::Gaming)),
-        )
-        .add_event::<TurretTriggered>()
-        .add_systems(
-            Update,
-            (
-                spawn_turrets,
-                cooldown_turrets,
-                despawn_turrets,
-                trigger_player_turrets,
-                trigger_enemy_turrets,
+        app.add_plugins(beam::BeamPlugin)
+            .add_systems(
+                Update,
+                (
+                    reposition_turrets
+                        .after(move_ships)
+                        .after(steer_ships)
+                        .after(rotate_stations),
+                    update_player_turret_targets,
+                    update_enemy_turret_targets.after(update_detection),
+                    update_ally_turret_targets.after(update_detection),
+                    rotate_turrets.after(fetch_aim_world_coords),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Gaming)),
             )
-                .run_if(in_state(GameState::Gaming)),
-        );
+            .add_event::<TurretTriggered>()
+            .add_systems(
+                Update,
+                (
+                    spawn_turrets,
+                    attach_turret_hitboxes,
+                    wreck_turrets,
+                    cooldown_turrets,
+                    despawn_turrets,
+                    trigger_player_turrets.after(update_actions),
+                    trigger_ai_turrets,
+                )
+                    .run_if(in_state(GameState::Gaming)),
+            );
     }
 }
 
@@ -48,6 +63,11 @@ pub enum TurretType {
     Cannon,
     Rocket,
     MediumRocket,
+    /// Drops naval mines behind the vessel.
+    MineLayer,
+    Torpedo,
+    /// Continuous ray that damages the first hostile it touches, limited by heat.
+    Beam,
 }
 
 #[derive(Component, Clone)]
@@ -56,9 +76,12 @@ pub struct Turret {
     pub stats_scale: f32,
     pub source: Entity,
     pub target_point: Vec2,
+    pub has_target: bool,
     pub offset: Vec3,
     pub cooling_down: bool,
     pub cooldown_timer: Timer,
+    /// Fraction of the normal reload speed, lowered when the mount takes damage.
+    pub reload_factor: f32,
 }
 
 impl Turret {
@@ -68,12 +91,14 @@ impl Turret {
             stats_scale,
             source,
             target_point: Vec2::default(),
+            has_target: false,
             offset: offset.extend(0.0),
             cooling_down: false,
             cooldown_timer: Timer::new(
                 Duration::from_secs_f32(cooldown_from_turret_type(turret_type, stats_scale)),
                 TimerMode::Repeating,
             ),
+            reload_factor: 1.0,
         }
     }
 }
@@ -83,8 +108,20 @@ pub struct TurretStats {
     pub turret_offsets: Vec<Vec2>,
 }
 
+/// Gives every turret of a vessel its own health and hitbox,
+/// so its weapons can be shot off before the hull goes down.
+#[derive(Component, Clone)]
+pub struct DestructibleTurrets {
+    pub health: f32,
+}
+
+/// A turret that got shot off. The mount stays as a wreck but never fires again.
+#[derive(Component)]
+pub struct TurretWrecked;
+
 #[derive(Event)]
 pub struct TurretTriggered {
+    pub turret: Entity,
     pub turret_type: TurretType,
     pub turret_layer: u32,
     pub turret_mask: u32,
@@ -100,6 +137,33 @@ fn cooldown_from_turret_type(turret_type: TurretType, stats_scale: f32) -> f32 {
         TurretType::Cannon => 0.1 / stats_scale,
         TurretType::Rocket => 0.5 / stats_scale,
         TurretType::MediumRocket => 5.0 / stats_scale,
+        TurretType::MineLayer => 2.0 / stats_scale,
+        TurretType::Torpedo => 6.0 / stats_scale,
+        // Only keeps the beam held while triggered, so it has to stay below `beam::HOLD_TIME`
+        // and ignores `stats_scale` and `reload_factor`. Its damage scales instead.
+        TurretType::Beam => 0.05,
+    }
+}
+
+fn hitbox_radius_from_turret_type(turret_type: TurretType) -> f32 {
+    match turret_type {
+        TurretType::Cannon => 12.0,
+        TurretType::Rocket => 12.0,
+        TurretType::MediumRocket => 24.0,
+        TurretType::MineLayer => 12.0,
+        TurretType::Torpedo => 12.0,
+        TurretType::Beam => 12.0,
+    }
+}
+
+fn range_from_turret_type(turret_type: TurretType) -> f32 {
+    match turret_type {
+        TurretType::Cannon => 1500.0,
+        TurretType::Rocket => 1100.0,
+        TurretType::MediumRocket => 1500.0,
+        TurretType::MineLayer => 700.0,
+        TurretType::Torpedo => 1800.0,
+        TurretType::Beam => 1200.0,
     }
 }
 
@@ -120,27 +184,96 @@ fn spawn_turrets(
                 Err(_) => continue,
             };
 
-            let texture = match turret_type {
-                TurretType::Cannon => assets.cannon_turret.clone(),
-                TurretType::Rocket => assets.rocket_turret.clone(),
-                TurretType::MediumRocket => assets.medium_rocket_turret.clone(),
-            };
-            commands.spawn((
-                SpriteBundle {
-                    texture,
-                    ..default()
-                },
+            spawn_turret(
+                &mut commands,
+                &assets,
                 Turret::new(
                     turret_type.clone(),
                     ev.stats_scale,
                     ev.entity,
                     turret_stats.turret_offsets[i],
                 ),
-            ));
+            );
         }
     }
 }
 
+pub fn spawn_turret(commands: &mut Commands, assets: &GameAssets, turret: Turret) -> Entity {
+    let texture = match turret.turret_type {
+        TurretType::Cannon => assets.cannon_turret.clone(),
+        TurretType::Rocket => assets.rocket_turret.clone(),
+        TurretType::MediumRocket => assets.medium_rocket_turret.clone(),
+        TurretType::MineLayer => assets.mine_layer_turret.clone(),
+        TurretType::Torpedo => assets.torpedo_turret.clone(),
+        TurretType::Beam => assets.beam_turret.clone(),
+    };
+    commands
+        .spawn((
+            SpriteBundle {
+                texture,
+                ..default()
+            },
+            turret,
+        ))
+        .id()
+}
+
+/// Turrets share the collision layer of their vessel, so they're hit by the same projectiles.
+fn attach_turret_hitboxes(
+    mut commands: Commands,
+    q_turrets: Query<(Entity, &Turret), Added<Turret>>,
+    q_sources: Query<(&DestructibleTurrets, &CollisionGroups)>,
+) {
+    for (entity, turret) in &q_turrets {
+        let (destructible, groups) = match q_sources.get(turret.source) {
+            Ok(s) => s,
+            Err(_) => continue,
+        };
+        commands.entity(entity).insert((
+            Health::new(entity, destructible.health * turret.stats_scale, 0.5),
+            Collider::ball(hitbox_radius_from_turret_type(turret.turret_type)),
+            // Torpedoes pass below the mounts and only hit the hull.
+            CollisionGroups::new(
+                groups.memberships,
+                groups.filters - Group::from_bits(TORPEDO_LAYER).unwrap(),
+            ),
+        ));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn wreck_turrets(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_turrets: Query<
+        (Entity, &Health, &mut Turret, &mut Handle<Image>),
+        (Changed<Health>, Without<TurretWrecked>),
+    >,
+) {
+    for (entity, health, mut turret, mut texture) in &mut q_turrets {
+        if health.health > 0.0 {
+            continue;
+        }
+
+        wreck_turret(&mut commands, &assets, entity, &mut turret, &mut texture);
+    }
+}
+
+pub fn wreck_turret(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    entity: Entity,
+    turret: &mut Turret,
+    texture: &mut Handle<Image>,
+) {
+    turret.has_target = false;
+    *texture = assets.wrecked_turret.clone();
+    commands
+        .entity(entity)
+        .insert(TurretWrecked)
+        .remove::<(Collider, CollisionGroups)>();
+}
+
 fn reposition_turrets(
     mut q_turrets: Query<(&mut Transform, &Turret)>,
     q_transforms: Query<&Transform, Without<Turret>>,
@@ -156,16 +289,16 @@ fn reposition_turrets(
     }
 }
 
-fn rotate_turrets(mut turrets: Query<(&mut Transform, &Turret)>) {
+fn rotate_turrets(mut turrets: Query<(&mut Transform, &Turret), Without<TurretWrecked>>) {
     for (mut transform, turret) in &mut turrets {
         transform.rotation = quat_from_vec2(turret.target_point - transform.translation.truncate());
     }
 }
 
 fn update_player_turret_targets(
-    mut q_turrets: Query<&mut Turret>,
+    mut q_turrets: Query<&mut Turret, Without<TurretWrecked>>,
     q_player: Query<Entity, With<Player>>,
-    mouse_coords: Res<MouseWorldCoords>,
+    aim_coords: Res<AimWorldCoords>,
 ) {
     let player = match q_player.get_single() {
         Ok(p) => p,
@@ -179,16 +312,20 @@ fn update_player_turret_targets(
         if turret.source != player {
             continue;
         }
-        turret.target_point = mouse_coords.0;
+        turret.target_point = aim_coords.0;
     }
 }
 
-fn update_enemy_turret_targets(
-    mut turrets: Query<&mut Turret>,
-    q_player: Query<&Transform, With<Player>>,
+/// Enemy turrets go for detected high value targets in range first, then the player.
+#[allow(clippy::type_complexity)]
+pub fn update_enemy_turret_targets(
+    mut turrets: Query<(&mut Turret, &Transform), Without<TurretWrecked>>,
+    q_player: Query<(&Transform, &Detected), With<Player>>,
+    q_priority: Query<(&Transform, &Detected), (With<HighValueTarget>, Without<Enemy>)>,
     q_enemies: Query<Entity, With<Enemy>>,
+    effects: Res<WeatherEffects>,
 ) {
-    let player_transform = match q_player.get_single() {
+    let (player_transform, player_detected) = match q_player.get_single() {
         Ok(p) => p,
         Err(err) => {
             error!("not exactly one player, {}", err);
@@ -196,11 +333,57 @@ fn update_enemy_turret_targets(
         }
     };
 
-    for mut turret in &mut turrets {
+    let player_pos = player_transform.translation.truncate();
+    for (mut turret, transform) in &mut turrets {
         if q_enemies.get(turret.source).is_err() {
             continue;
         }
-        turret.target_point = player_transform.translation.truncate();
+
+        let pos = transform.translation.truncate();
+        let range = range_from_turret_type(turret.turret_type) * effects.turret_range_factor;
+        let priority = q_priority
+            .iter()
+            .filter(|(_, detected)| detected.0)
+            .map(|(t, _)| t.translation.truncate())
+            .filter(|target| target.distance(pos) <= range)
+            .min_by(|a, b| a.distance(pos).total_cmp(&b.distance(pos)));
+
+        if let Some(target) = priority {
+            turret.has_target = true;
+            turret.target_point = target;
+            continue;
+        }
+
+        turret.has_target = player_detected.0;
+        if turret.has_target {
+            turret.target_point = player_pos;
+        }
+    }
+}
+
+/// Turrets of allied vessels aim at the closest enemy their side has detected.
+#[allow(clippy::type_complexity)]
+fn update_ally_turret_targets(
+    mut turrets: Query<(&mut Turret, &Transform), Without<TurretWrecked>>,
+    q_allies: Query<(), (With<TurretStats>, Without<Player>, Without<Enemy>)>,
+    q_enemies: Query<(&Transform, &Detected), With<Enemy>>,
+) {
+    for (mut turret, transform) in &mut turrets {
+        if q_allies.get(turret.source).is_err() {
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        let closest = q_enemies
+            .iter()
+            .filter(|(_, detected)| detected.0)
+            .map(|(t, _)| t.translation.truncate())
+            .min_by(|a, b| a.distance(pos).total_cmp(&b.distance(pos)));
+
+        turret.has_target = closest.is_some();
+        if let Some(target) = closest {
+            turret.target_point = target;
+        }
     }
 }
 
@@ -210,7 +393,12 @@ fn cooldown_turrets(time: Res<Time>, mut q_turrets: Query<&mut Turret>) {
             continue;
         }
 
-        turret.cooldown_timer.tick(time.delta());
+        let reload_factor = match turret.turret_type {
+            TurretType::Beam => 1.0,
+            _ => turret.reload_factor,
+        };
+        let delta = time.delta().mul_f32(reload_factor);
+        turret.cooldown_timer.tick(delta);
 
         if turret.cooldown_timer.just_finished() {
             turret.cooling_down = false;
@@ -231,12 +419,12 @@ fn despawn_turrets(
 }
 
 fn trigger_player_turrets(
-    buttons: Res<Input<MouseButton>>,
-    mut q_turrets: Query<(&mut Turret, &Transform)>,
+    actions: Res<ActionInput>,
+    mut q_turrets: Query<(Entity, &mut Turret, &Transform), Without<TurretWrecked>>,
     q_player: Query<(Entity, &Transform, &ShipStats), With<Player>>,
     mut ev_rocket_fired: EventWriter<TurretTriggered>,
 ) {
-    if !buttons.pressed(MouseButton::Left) {
+    if !actions.pressed(Action::Fire) {
         return;
     }
 
@@ -248,7 +436,7 @@ fn trigger_player_turrets(
         }
     };
 
-    for (mut turret, transform) in &mut q_turrets {
+    for (entity, mut turret, transform) in &mut q_turrets {
         if turret.cooling_down {
             continue;
         }
@@ -258,6 +446,7 @@ fn trigger_player_turrets(
         }
 
         ev_rocket_fired.send(TurretTriggered {
+            turret: entity,
             turret_type: turret.turret_type,
             turret_layer: PLAYER_LAYER,
             turret_mask: ENEMY_LAYER,
@@ -271,28 +460,47 @@ fn trigger_player_turrets(
     }
 }
 
-fn trigger_enemy_turrets(
-    mut q_turrets: Query<(&mut Turret, &Transform)>,
-    q_enemies: Query<&Transform, With<Enemy>>,
+/// Fire turrets of AI vessels, enemies and allies alike, at their targets.
+#[allow(clippy::type_complexity)]
+pub fn trigger_ai_turrets(
+    mut q_turrets: Query<(Entity, &mut Turret, &Transform), Without<TurretWrecked>>,
+    q_sources: Query<(&Transform, Has<Enemy>), (With<TurretStats>, Without<Player>)>,
+    effects: Res<WeatherEffects>,
     mut ev_turret_triggered: EventWriter<TurretTriggered>,
 ) {
-    for (mut turret, transform) in &mut q_turrets {
-        if turret.cooling_down {
+    for (entity, mut turret, transform) in &mut q_turrets {
+        if turret.cooling_down || !turret.has_target {
             continue;
         }
 
-        let e_transform = match q_enemies.get(turret.source) {
-            Ok(t) => t,
+        let range = range_from_turret_type(turret.turret_type) * effects.turret_range_factor;
+        if transform
+            .translation
+            .truncate()
+            .distance(turret.target_point)
+            > range
+        {
+            continue;
+        }
+
+        let (s_transform, is_enemy) = match q_sources.get(turret.source) {
+            Ok(s) => s,
             Err(_) => continue,
         };
+        let (turret_layer, turret_mask) = if is_enemy {
+            (ENEMY_LAYER, PLAYER_LAYER)
+        } else {
+            (PLAYER_LAYER, ENEMY_LAYER)
+        };
 
         ev_turret_triggered.send(TurretTriggered {
+            turret: entity,
             turret_type: turret.turret_type,
-            turret_layer: ENEMY_LAYER,
-            turret_mask: PLAYER_LAYER,
+            turret_layer,
+            turret_mask,
             source: turret.source,
             source_transform: transform.clone(),
-            source_velocity: e_transform.local_y().truncate(),
+            source_velocity: s_transform.local_y().truncate(),
             stats_scale: turret.stats_scale,
             target_point: turret.target_point,
         });
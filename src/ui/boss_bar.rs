@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use crate::{enemy::boss::Boss, GameState};
+
+use super::health::Health;
+
+const BAR_WIDTH: f32 = 600.0;
+const BAR_HEIGHT: f32 = 18.0;
+const FILL_COLOR: Color = Color::rgb(0.75, 0.05, 0.1);
+const PHASE_MARKER_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.6);
+
+#[derive(Component)]
+struct BossBar;
+
+#[derive(Component)]
+struct BossBarLabel;
+
+#[derive(Component)]
+struct BossBarFill;
+
+/// Notch at a phase threshold, positioned once the boss is known.
+#[derive(Component)]
+struct BossBarMarker;
+
+fn spawn_boss_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            BossBar,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    top: Val::Px(56.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                BossBarLabel,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 20.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.2, 0.2, 0.2).into(),
+                    ..default()
+                })
+                .with_children(|bar| {
+                    bar.spawn((
+                        BossBarFill,
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: FILL_COLOR.into(),
+                            ..default()
+                        },
+                    ));
+                });
+        });
+}
+
+fn spawn_phase_markers(
+    mut commands: Commands,
+    q_bosses: Query<&Boss, Added<Boss>>,
+    q_fill: Query<&Parent, With<BossBarFill>>,
+    q_markers: Query<Entity, With<BossBarMarker>>,
+) {
+    let boss = match q_bosses.iter().next() {
+        Some(b) => b,
+        None => return,
+    };
+    let bar = match q_fill.get_single() {
+        Ok(parent) => parent.get(),
+        Err(_) => return,
+    };
+
+    for marker in &q_markers {
+        commands.entity(marker).despawn_recursive();
+    }
+    for phase in boss.phases.iter().skip(1) {
+        let marker = commands
+            .spawn((
+                BossBarMarker,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(phase.health_threshold * 100.0),
+                        width: Val::Px(2.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: PHASE_MARKER_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .id();
+        commands.entity(bar).add_child(marker);
+    }
+}
+
+/// Show the engaged boss, there is only room for one on the HUD.
+fn update_boss_bar(
+    q_bosses: Query<(&Boss, &Health)>,
+    mut q_bar: Query<&mut Visibility, With<BossBar>>,
+    mut q_label: Query<&mut Text, With<BossBarLabel>>,
+    mut q_fill: Query<&mut Style, With<BossBarFill>>,
+) {
+    let boss = q_bosses.iter().find(|(boss, _)| boss.engaged);
+
+    for mut visibility in &mut q_bar {
+        *visibility = if boss.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let (boss, health) = match boss {
+        Some(b) => b,
+        None => return,
+    };
+
+    for mut text in &mut q_label {
+        text.sections[0].value = match (boss.current, boss.phase()) {
+            (Some(i), Some(phase)) => format!(
+                "{}  -  {} ({}/{})",
+                boss.name,
+                phase.name,
+                i + 1,
+                boss.phases.len()
+            ),
+            _ => boss.name.clone(),
+        };
+    }
+    for mut style in &mut q_fill {
+        let fill = (health.health / health.max_health).clamp(0.0, 1.0);
+        style.width = Val::Percent(fill * 100.0);
+    }
+}
+
+pub struct BossBarPlugin;
+
+impl Plugin for BossBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Gaming), spawn_boss_bar)
+            .add_systems(
+                Update,
+                (spawn_phase_markers, update_boss_bar).run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
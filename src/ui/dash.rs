@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use crate::{player::Player, vessel::ship::DashEnergy, GameState};
+
+const READY_COLOR: Color = Color::rgb(0.1, 0.7, 0.9);
+const NOT_READY_COLOR: Color = Color::rgb(0.3, 0.4, 0.5);
+
+#[derive(Component)]
+struct DashMeterFill;
+
+fn spawn_dash_meter(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                width: Val::Px(200.0),
+                height: Val::Px(12.0),
+                ..default()
+            },
+            background_color: Color::rgb(0.2, 0.2, 0.2).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                DashMeterFill,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: READY_COLOR.into(),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn fill_dash_meter(
+    q_player: Query<&DashEnergy, With<Player>>,
+    mut q_fill: Query<(&mut Style, &mut BackgroundColor), With<DashMeterFill>>,
+) {
+    let dash_energy = match q_player.get_single() {
+        Ok(d) => d,
+        Err(_) => return,
+    };
+
+    for (mut style, mut color) in &mut q_fill {
+        let fill = (dash_energy.energy / dash_energy.max_energy).clamp(0.0, 1.0);
+        style.width = Val::Percent(fill * 100.0);
+        *color = if dash_energy.ready() {
+            READY_COLOR.into()
+        } else {
+            NOT_READY_COLOR.into()
+        };
+    }
+}
+
+pub struct DashMeterPlugin;
+
+impl Plugin for DashMeterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Gaming), spawn_dash_meter)
+            .add_systems(
+                Update,
+                (fill_dash_meter,).run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use crate::{player::Player, vessel::ship::drift::DriftState, GameState};
+
+#[derive(Component)]
+struct DriftScoreText;
+
+fn spawn_drift_score(mut commands: Commands) {
+    commands.spawn((
+        DriftScoreText,
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            bottom: Val::Px(40.0),
+            ..default()
+        }),
+    ));
+}
+
+fn update_drift_score(
+    q_player: Query<&DriftState, (With<Player>, Changed<DriftState>)>,
+    mut q_text: Query<&mut Text, With<DriftScoreText>>,
+) {
+    let drift = match q_player.get_single() {
+        Ok(d) => d,
+        Err(_) => return,
+    };
+
+    for mut text in &mut q_text {
+        text.sections[0].value = if drift.combo > 1 {
+            format!("Drift {:.0}  x{}", drift.score, drift.combo)
+        } else {
+            format!("Drift {:.0}", drift.score)
+        };
+    }
+}
+
+pub struct DriftScorePlugin;
+
+impl Plugin for DriftScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Gaming), spawn_drift_score)
+            .add_systems(
+                Update,
+                (update_drift_score,).run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
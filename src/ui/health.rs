@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 
 use crate::{
+    enemy::{boss::Boss, Enemy},
     projectile::ProjectileCollision,
-    vessel::{ship::move_ships, SpawnVessel},
+    vessel::{sensor::Detected, ship::move_ships, SpawnVessel},
     GameState,
 };
 
@@ -33,6 +34,13 @@ impl Health {
     }
 }
 
+/// Damage that doesn't come from a projectile hit, like a beam.
+#[derive(Event)]
+pub struct DealDamage {
+    pub target: Entity,
+    pub amount: f32,
+}
+
 #[derive(Component)]
 struct HealthBar {
     entity: Entity,
@@ -74,6 +82,7 @@ fn fill_health_bar(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn fill_health_bars(
     mut health_bars: Query<
         (&HealthBar, &Children, &mut Visibility),
@@ -83,14 +92,17 @@ fn fill_health_bars(
         (&mut Transform, &HealthBarFill),
         (Without<Health>, Without<HealthBar>),
     >,
-    healths: Query<&Health, Without<HealthBar>>,
+    healths: Query<(&Health, Option<&Detected>, Has<Enemy>), Without<HealthBar>>,
 ) {
     for (health_bar, children, mut health_bar_visibility) in &mut health_bars {
         *health_bar_visibility = Visibility::Hidden;
-        for health in &healths {
+        for (health, detected, is_enemy) in &healths {
             if health.entity != health_bar.entity {
                 continue;
             }
+            if is_enemy && detected.is_some_and(|d| !d.0) {
+                continue;
+            }
 
             *health_bar_visibility = Visibility::Visible;
             fill_health_bar(&mut health_bar_fills, children, health);
@@ -171,11 +183,18 @@ fn spawn_health_bar(commands: &mut Commands, ev: SpawnVessel) {
         .push_children(&[fill_container, background]);
 }
 
-fn spawn_health_bars(mut commands: Commands, mut ev_spawn_health: EventReader<SpawnVessel>) {
+/// Bosses get their bar on the HUD instead.
+fn spawn_health_bars(
+    mut commands: Commands,
+    q_bosses: Query<(), With<Boss>>,
+    mut ev_spawn_health: EventReader<SpawnVessel>,
+) {
     for ev in ev_spawn_health.read() {
         if let Some(mut entity) = commands.get_entity(ev.entity) {
             entity.insert(ev.health.clone());
-            spawn_health_bar(&mut commands, ev.clone());
+            if q_bosses.get(ev.entity).is_err() {
+                spawn_health_bar(&mut commands, ev.clone());
+            }
         }
     }
 }
@@ -191,6 +210,17 @@ fn apply_projectile_damage(
     }
 }
 
+fn apply_direct_damage(
+    mut q_healths: Query<&mut Health>,
+    mut ev_deal_damage: EventReader<DealDamage>,
+) {
+    for ev in ev_deal_damage.read() {
+        if let Ok(mut health) = q_healths.get_mut(ev.target) {
+            health.health -= ev.amount;
+        }
+    }
+}
+
 pub struct HealthPlugin;
 
 impl Plugin for HealthPlugin {
@@ -202,8 +232,10 @@ impl Plugin for HealthPlugin {
                 fill_health_bars,
                 spawn_health_bars,
                 apply_projectile_damage,
+                apply_direct_damage,
             )
                 .run_if(in_state(GameState::Gaming)),
-        );
+        )
+        .add_event::<DealDamage>();
     }
 }
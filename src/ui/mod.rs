@@ -1,4 +1,11 @@
+mod boss_bar;
+mod dash;
+mod drift;
 pub mod health;
+pub mod objective;
+mod radar;
+mod rebind;
+mod threat;
 
 use bevy::prelude::*;
 
@@ -6,6 +13,15 @@ pub struct GuardianUiPlugin;
 
 impl Plugin for GuardianUiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(health::HealthPlugin);
+        app.add_plugins((
+            health::HealthPlugin,
+            boss_bar::BossBarPlugin,
+            dash::DashMeterPlugin,
+            drift::DriftScorePlugin,
+            objective::ObjectivePlugin,
+            radar::RadarPlugin,
+            rebind::RebindPlugin,
+            threat::ThreatIndicatorPlugin,
+        ));
     }
 }
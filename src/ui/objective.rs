@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::GameState;
+
+/// Text shown at the top of the screen, set by the active game mode.
+#[derive(Resource, Default)]
+pub struct ObjectiveText(pub String);
+
+#[derive(Component)]
+struct ObjectiveLabel;
+
+fn spawn_objective_text(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Px(20.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                ObjectiveLabel,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 24.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+            ));
+        });
+}
+
+fn update_objective_text(
+    objective: Res<ObjectiveText>,
+    mut q_text: Query<&mut Text, With<ObjectiveLabel>>,
+) {
+    if !objective.is_changed() {
+        return;
+    }
+
+    for mut text in &mut q_text {
+        text.sections[0].value = objective.0.clone();
+    }
+}
+
+pub struct ObjectivePlugin;
+
+impl Plugin for ObjectivePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObjectiveText>()
+            .add_systems(OnEnter(GameState::Gaming), spawn_objective_text)
+            .add_systems(
+                Update,
+                (update_objective_text,).run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
@@ -0,0 +1,218 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::CollisionGroups;
+
+use crate::{
+    collision::PLAYER_LAYER,
+    enemy::Enemy,
+    player::Player,
+    projectile::{torpedo::Torpedo, Projectile},
+    turret::TurretStats,
+    vessel::{sensor::Detected, station::StationStats},
+    world::{terrain::Terrain, MainCamera},
+    GameState,
+};
+
+const RADAR_SIZE: f32 = 200.0;
+const PLAYER_COLOR: Color = Color::WHITE;
+const ALLY_COLOR: Color = Color::rgb(0.2, 0.9, 0.3);
+const ENEMY_COLOR: Color = Color::rgb(0.9, 0.15, 0.15);
+const PROJECTILE_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+
+/// Radius of the radar in world units at a camera scale of 1,
+/// the actual radius grows with the zoom level.
+#[derive(Resource)]
+pub struct RadarConfig {
+    pub range: f32,
+}
+
+impl Default for RadarConfig {
+    fn default() -> Self {
+        Self { range: 2000.0 }
+    }
+}
+
+#[derive(Component)]
+struct RadarPanel;
+
+#[derive(Component)]
+struct RadarBlip;
+
+type VesselQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        Has<Enemy>,
+        Has<StationStats>,
+        Option<&'static Detected>,
+    ),
+    (With<TurretStats>, Without<Player>),
+>;
+
+/// Submerged torpedoes don't show up, only their wake gives them away.
+type ProjectileQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static CollisionGroups),
+    (With<Projectile>, Without<Torpedo>),
+>;
+
+struct Blip {
+    offset: Vec2,
+    size: f32,
+    color: Color,
+}
+
+fn spawn_radar(mut commands: Commands) {
+    commands.spawn((
+        RadarPanel,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(20.0),
+                width: Val::Px(RADAR_SIZE),
+                height: Val::Px(RADAR_SIZE),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.1, 0.05, 0.7).into(),
+            ..default()
+        },
+    ));
+}
+
+fn collect_blips(
+    player_pos: Vec2,
+    range: f32,
+    q_vessels: &VesselQuery,
+    q_terrain: &Query<(&Transform, &Terrain)>,
+    q_projectiles: &ProjectileQuery,
+) -> Vec<Blip> {
+    let px_per_unit = RADAR_SIZE / 2.0 / range;
+    let in_range = |offset: Vec2, margin: f32| {
+        offset.x.abs() <= range + margin && offset.y.abs() <= range + margin
+    };
+    let mut blips = Vec::new();
+
+    for (transform, terrain) in q_terrain {
+        let offset = transform.translation.truncate() - player_pos;
+        if in_range(offset, terrain.radius) {
+            blips.push(Blip {
+                offset,
+                size: (terrain.radius * 2.0 * px_per_unit).max(2.0),
+                color: terrain.kind.color(),
+            });
+        }
+    }
+
+    for (transform, groups) in q_projectiles {
+        let offset = transform.translation.truncate() - player_pos;
+        if groups.filters.bits() & PLAYER_LAYER != 0 && in_range(offset, 0.0) {
+            blips.push(Blip {
+                offset,
+                size: 3.0,
+                color: PROJECTILE_COLOR,
+            });
+        }
+    }
+
+    for (transform, is_enemy, is_station, detected) in q_vessels {
+        if is_enemy && detected.is_some_and(|d| !d.0) {
+            continue;
+        }
+        let offset = transform.translation.truncate() - player_pos;
+        if in_range(offset, 0.0) {
+            blips.push(Blip {
+                offset,
+                size: if is_station { 10.0 } else { 6.0 },
+                color: if is_enemy { ENEMY_COLOR } else { ALLY_COLOR },
+            });
+        }
+    }
+
+    blips.push(Blip {
+        offset: Vec2::ZERO,
+        size: 6.0,
+        color: PLAYER_COLOR,
+    });
+
+    for blip in &mut blips {
+        blip.offset *= px_per_unit;
+    }
+    blips
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_radar(
+    mut commands: Commands,
+    config: Res<RadarConfig>,
+    q_camera: Query<&OrthographicProjection, With<MainCamera>>,
+    q_player: Query<&Transform, With<Player>>,
+    q_vessels: VesselQuery,
+    q_terrain: Query<(&Transform, &Terrain)>,
+    q_projectiles: ProjectileQuery,
+    q_panel: Query<Entity, With<RadarPanel>>,
+    mut q_blips: Query<(&mut Style, &mut BackgroundColor, &mut Visibility), With<RadarBlip>>,
+) {
+    let (player_transform, projection, panel) = match (
+        q_player.get_single(),
+        q_camera.get_single(),
+        q_panel.get_single(),
+    ) {
+        (Ok(p), Ok(c), Ok(r)) => (p, c, r),
+        _ => return,
+    };
+
+    let range = config.range * projection.scale;
+    let blips = collect_blips(
+        player_transform.translation.truncate(),
+        range,
+        &q_vessels,
+        &q_terrain,
+        &q_projectiles,
+    );
+
+    let mut blips = blips.into_iter();
+    for (mut style, mut color, mut visibility) in &mut q_blips {
+        match blips.next() {
+            Some(blip) => {
+                style.left = Val::Px(RADAR_SIZE / 2.0 + blip.offset.x - blip.size / 2.0);
+                style.top = Val::Px(RADAR_SIZE / 2.0 - blip.offset.y - blip.size / 2.0);
+                style.width = Val::Px(blip.size);
+                style.height = Val::Px(blip.size);
+                color.0 = blip.color;
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+
+    // Not enough blips in the pool, they will show up next frame.
+    for _ in blips {
+        let blip = commands
+            .spawn((
+                RadarBlip,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+            ))
+            .id();
+        commands.entity(panel).add_child(blip);
+    }
+}
+
+pub struct RadarPlugin;
+
+impl Plugin for RadarPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RadarConfig>()
+            .add_systems(OnEnter(GameState::Gaming), spawn_radar)
+            .add_systems(Update, (update_radar,).run_if(in_state(GameState::Gaming)));
+    }
+}
@@ -0,0 +1,375 @@
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+
+use crate::player::input::action::{update_actions, Action, ActionInput, Binding, InputBindings};
+use crate::world::camera::CameraSettings;
+use crate::GameState;
+
+const REBIND_THRESHOLD: f32 = 0.5;
+const BUTTON_COLOR: Color = Color::rgb(0.15, 0.15, 0.2);
+const HOVERED_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.35);
+const LISTENING_BUTTON_COLOR: Color = Color::rgb(0.5, 0.3, 0.1);
+/// Screen shake strengths the shake button cycles through.
+const SHAKE_LEVELS: [f32; 3] = [1.0, 0.5, 0.0];
+
+#[derive(Resource, Default)]
+struct RebindMenu {
+    open: bool,
+    /// The action and slot (negative or not) waiting for a new binding.
+    listening: Option<(Action, bool)>,
+}
+
+#[derive(Component)]
+struct RebindMenuRoot;
+
+#[derive(Component, Clone, Copy)]
+struct RebindButton {
+    action: Action,
+    negative: bool,
+}
+
+#[derive(Component)]
+struct RebindLabel {
+    action: Action,
+    negative: bool,
+}
+
+#[derive(Component)]
+struct ShakeButton;
+
+#[derive(Component)]
+struct ShakeLabel;
+
+fn slot_name(action: Action, negative: bool) -> String {
+    if !action.is_axis() {
+        return format!("{:?}", action);
+    }
+    format!("{:?} {}", action, if negative { "-" } else { "+" })
+}
+
+fn spawn_rebind_button(parent: &mut ChildBuilder, action: Action, negative: bool) {
+    parent
+        .spawn((
+            RebindButton { action, negative },
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(500.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: BUTTON_COLOR.into(),
+                ..default()
+            },
+        ))
+        .with_children(|button| {
+            button.spawn((
+                RebindLabel { action, negative },
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 20.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+            ));
+        });
+}
+
+fn spawn_rebind_menu(mut commands: Commands) {
+    commands
+        .spawn((
+            RebindMenuRoot,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ))
+        .with_children(|root| {
+            root.spawn(TextBundle::from_section(
+                "Click a binding, then press a key or button. Esc to close.",
+                TextStyle {
+                    font_size: 24.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+
+            for action in Action::ALL {
+                spawn_rebind_button(root, action, false);
+                if action.is_axis() {
+                    spawn_rebind_button(root, action, true);
+                }
+            }
+
+            root.spawn((
+                ShakeButton,
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(500.0),
+                        padding: UiRect::all(Val::Px(6.0)),
+                        margin: UiRect::top(Val::Px(12.0)),
+                        ..default()
+                    },
+                    background_color: BUTTON_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    ShakeLabel,
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 20.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                ));
+            });
+        });
+}
+
+fn toggle_rebind_menu(
+    keys: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mut menu: ResMut<RebindMenu>,
+    mut actions: ResMut<ActionInput>,
+    mut time: ResMut<Time<Virtual>>,
+    mut q_root: Query<&mut Visibility, With<RebindMenuRoot>>,
+) {
+    if menu.listening.is_some() {
+        return;
+    }
+
+    let pressed = keys.just_pressed(KeyCode::Escape)
+        || gamepad_buttons
+            .get_just_pressed()
+            .any(|button| button.button_type == GamepadButtonType::Start);
+    if !pressed {
+        return;
+    }
+
+    menu.open = !menu.open;
+    actions.set_disabled(menu.open);
+    if menu.open {
+        time.pause();
+    } else {
+        time.unpause();
+    }
+
+    for mut visibility in &mut q_root {
+        *visibility = if menu.open {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn captured_binding(
+    keys: &Input<KeyCode>,
+    mouse_buttons: &Input<MouseButton>,
+    ev_scroll: &mut EventReader<MouseWheel>,
+    gamepads: &Gamepads,
+    gamepad_buttons: &Input<GamepadButton>,
+    axes: &Axis<GamepadAxis>,
+) -> Option<(Binding, bool)> {
+    if let Some(key) = keys.get_just_pressed().next() {
+        return Some((Binding::Key(*key), false));
+    }
+    if let Some(button) = mouse_buttons.get_just_pressed().next() {
+        return Some((Binding::Mouse(*button), false));
+    }
+    if ev_scroll.read().last().is_some() {
+        return Some((Binding::MouseWheel, false));
+    }
+    if let Some(button) = gamepad_buttons.get_just_pressed().next() {
+        return Some((Binding::GamepadButton(button.button_type), false));
+    }
+
+    for gamepad in gamepads.iter() {
+        for axis_type in [
+            GamepadAxisType::LeftStickX,
+            GamepadAxisType::LeftStickY,
+            GamepadAxisType::RightStickX,
+            GamepadAxisType::RightStickY,
+        ] {
+            let value = axes
+                .get(GamepadAxis::new(gamepad, axis_type))
+                .unwrap_or(0.0);
+            if value.abs() > REBIND_THRESHOLD {
+                // Pushing the stick the "wrong" way flips the slot it ends up in.
+                return Some((Binding::GamepadAxis(axis_type), value < 0.0));
+            }
+        }
+    }
+    None
+}
+
+#[allow(clippy::too_many_arguments)]
+fn capture_rebind(
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut ev_scroll: EventReader<MouseWheel>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    mut menu: ResMut<RebindMenu>,
+    mut bindings: ResMut<InputBindings>,
+) {
+    let (action, negative) = match menu.listening {
+        Some(l) => l,
+        None => return,
+    };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        menu.listening = None;
+        return;
+    }
+
+    let (binding, flipped) = match captured_binding(
+        &keys,
+        &mouse_buttons,
+        &mut ev_scroll,
+        &gamepads,
+        &gamepad_buttons,
+        &axes,
+    ) {
+        Some(b) => b,
+        None => return,
+    };
+
+    bindings
+        .0
+        .entry(action)
+        .or_default()
+        .rebind(negative != flipped, binding);
+    bindings.save();
+    menu.listening = None;
+}
+
+fn select_rebind_slot(
+    mut menu: ResMut<RebindMenu>,
+    mut q_buttons: Query<(&Interaction, &RebindButton), Changed<Interaction>>,
+) {
+    if !menu.open || menu.listening.is_some() {
+        return;
+    }
+
+    for (interaction, button) in &mut q_buttons {
+        if *interaction == Interaction::Pressed {
+            menu.listening = Some((button.action, button.negative));
+        }
+    }
+}
+
+fn cycle_shake_setting(
+    menu: Res<RebindMenu>,
+    mut settings: ResMut<CameraSettings>,
+    q_button: Query<&Interaction, (With<ShakeButton>, Changed<Interaction>)>,
+) {
+    if !menu.open || menu.listening.is_some() {
+        return;
+    }
+
+    for interaction in &q_button {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let current = SHAKE_LEVELS
+            .iter()
+            .position(|level| *level == settings.shake_intensity)
+            .unwrap_or(0);
+        settings.shake_intensity = SHAKE_LEVELS[(current + 1) % SHAKE_LEVELS.len()];
+    }
+}
+
+fn update_shake_button(
+    settings: Res<CameraSettings>,
+    mut q_button: Query<(&Interaction, &mut BackgroundColor), With<ShakeButton>>,
+    mut q_label: Query<&mut Text, With<ShakeLabel>>,
+) {
+    for (interaction, mut color) in &mut q_button {
+        *color = if *interaction == Interaction::Hovered {
+            HOVERED_BUTTON_COLOR.into()
+        } else {
+            BUTTON_COLOR.into()
+        };
+    }
+
+    for mut text in &mut q_label {
+        text.sections[0].value = format!("Screen shake: {:.0}%", settings.shake_intensity * 100.0);
+    }
+}
+
+fn update_rebind_buttons(
+    menu: Res<RebindMenu>,
+    bindings: Res<InputBindings>,
+    mut q_buttons: Query<(&Interaction, &RebindButton, &mut BackgroundColor)>,
+    mut q_labels: Query<(&RebindLabel, &mut Text)>,
+) {
+    for (interaction, button, mut color) in &mut q_buttons {
+        *color = if menu.listening == Some((button.action, button.negative)) {
+            LISTENING_BUTTON_COLOR.into()
+        } else if *interaction == Interaction::Hovered {
+            HOVERED_BUTTON_COLOR.into()
+        } else {
+            BUTTON_COLOR.into()
+        };
+    }
+
+    if !menu.is_changed() && !bindings.is_changed() {
+        return;
+    }
+
+    for (label, mut text) in &mut q_labels {
+        let bound = if menu.listening == Some((label.action, label.negative)) {
+            "press a key or button...".to_string()
+        } else {
+            bindings
+                .get(label.action)
+                .slot(label.negative)
+                .iter()
+                .map(|b| b.label())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        text.sections[0].value = format!("{}: {}", slot_name(label.action, label.negative), bound);
+    }
+}
+
+pub struct RebindPlugin;
+
+impl Plugin for RebindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RebindMenu>()
+            .add_systems(OnEnter(GameState::Gaming), spawn_rebind_menu)
+            .add_systems(
+                Update,
+                (
+                    toggle_rebind_menu,
+                    capture_rebind,
+                    select_rebind_slot,
+                    update_rebind_buttons,
+                    cycle_shake_setting,
+                    update_shake_button,
+                )
+                    .chain()
+                    .before(update_actions)
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_rapier2d::prelude::CollisionGroups;
+
+use crate::{
+    collision::PLAYER_LAYER,
+    enemy::Enemy,
+    player::Player,
+    projectile::{torpedo::Torpedo, Projectile},
+    turret::{Turret, TurretStats},
+    vessel::sensor::Detected,
+    world::{camera::move_camera, MainCamera},
+    GameState,
+};
+
+/// Distance between the indicators and the screen edge, in pixels.
+const EDGE_MARGIN: f32 = 30.0;
+const MAX_ARROW_SIZE: f32 = 28.0;
+const MIN_ARROW_SIZE: f32 = 10.0;
+/// Beyond this distance from the view the arrows stop shrinking.
+const FAR_DISTANCE: f32 = 4000.0;
+/// Number of hostile projectiles closing in on the player that counts as a volley.
+const VOLLEY_SIZE: usize = 8;
+/// Only projectiles this close to the player are part of an incoming volley.
+const VOLLEY_RANGE: f32 = 2500.0;
+const FLASH_RATE: f32 = 6.0;
+
+const IDLE_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+const TRACKING_COLOR: Color = Color::rgb(1.0, 0.5, 0.1);
+const FIRING_COLOR: Color = Color::rgb(1.0, 0.1, 0.1);
+const VOLLEY_COLOR: Color = Color::rgb(1.0, 0.2, 0.9);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Threat {
+    Idle,
+    /// At least one turret has the player as target.
+    Tracking,
+    /// At least one turret is targeting the player and just fired.
+    Firing,
+}
+
+impl Threat {
+    fn color(&self) -> Color {
+        match self {
+            Threat::Idle => IDLE_COLOR,
+            Threat::Tracking => TRACKING_COLOR,
+            Threat::Firing => FIRING_COLOR,
+        }
+    }
+}
+
+#[derive(Component)]
+struct VolleyWarning;
+
+fn spawn_volley_warning(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Px(60.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                VolleyWarning,
+                TextBundle {
+                    visibility: Visibility::Hidden,
+                    ..TextBundle::from_section(
+                        "INCOMING VOLLEY",
+                        TextStyle {
+                            font_size: 36.0,
+                            color: VOLLEY_COLOR,
+                            ..default()
+                        },
+                    )
+                },
+            ));
+        });
+}
+
+/// The point where the line from the view centre to `target` leaves
+/// the view, shrunk by `margin` on every side.
+fn edge_point(center: Vec2, half_size: Vec2, margin: f32, target: Vec2) -> Vec2 {
+    let half_size = (half_size - Vec2::splat(margin)).max(Vec2::ZERO);
+    let dir = target - center;
+    let tx = if dir.x != 0.0 {
+        half_size.x / dir.x.abs()
+    } else {
+        f32::MAX
+    };
+    let ty = if dir.y != 0.0 {
+        half_size.y / dir.y.abs()
+    } else {
+        f32::MAX
+    };
+    center + dir * tx.min(ty)
+}
+
+fn draw_arrow(gizmos: &mut Gizmos, tip: Vec2, dir: Vec2, size: f32, color: Color) {
+    let back = tip - dir * size;
+    let side = dir.perp() * size * 0.5;
+    gizmos.linestrip_2d([tip, back + side, back - side, tip], color);
+}
+
+fn arrow_size(distance: f32, scale: f32) -> f32 {
+    let t = (distance / FAR_DISTANCE).clamp(0.0, 1.0);
+    (MAX_ARROW_SIZE + (MIN_ARROW_SIZE - MAX_ARROW_SIZE) * t) * scale
+}
+
+fn view_rect(transform: &Transform, projection: &OrthographicProjection) -> Rect {
+    Rect::from_center_half_size(
+        transform.translation.truncate(),
+        projection.area.half_size(),
+    )
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_threat_indicators(
+    mut gizmos: Gizmos,
+    q_camera: Query<(&Transform, &OrthographicProjection), With<MainCamera>>,
+    q_enemies: Query<(Entity, &Transform, &Detected), (With<Enemy>, With<TurretStats>)>,
+    q_turrets: Query<&Turret>,
+) {
+    let (camera_transform, projection) = match q_camera.get_single() {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let view = view_rect(camera_transform, projection);
+    let margin = EDGE_MARGIN * projection.scale;
+
+    let mut threats: HashMap<Entity, Threat> = HashMap::new();
+    for turret in &q_turrets {
+        let threat = match (turret.has_target, turret.cooling_down) {
+            (true, true) => Threat::Firing,
+            (true, false) => Threat::Tracking,
+            _ => Threat::Idle,
+        };
+        let entry = threats.entry(turret.source).or_insert(Threat::Idle);
+        *entry = (*entry).max(threat);
+    }
+
+    for (entity, transform, detected) in &q_enemies {
+        let pos = transform.translation.truncate();
+        if !detected.0 || view.contains(pos) {
+            continue;
+        }
+
+        let tip = edge_point(view.center(), view.half_size(), margin, pos);
+        let threat = threats.get(&entity).copied().unwrap_or(Threat::Idle);
+        let size = arrow_size(tip.distance(pos), projection.scale);
+        let dir = (pos - view.center()).normalize_or_zero();
+        draw_arrow(&mut gizmos, tip, dir, size, threat.color());
+    }
+}
+
+/// Torpedoes run submerged and don't count towards a volley.
+#[allow(clippy::type_complexity)]
+fn warn_incoming_volleys(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    q_camera: Query<(&Transform, &OrthographicProjection), With<MainCamera>>,
+    q_player: Query<&Transform, With<Player>>,
+    q_projectiles: Query<(&Transform, &CollisionGroups), (With<Projectile>, Without<Torpedo>)>,
+    mut q_warning: Query<&mut Visibility, With<VolleyWarning>>,
+) {
+    let ((camera_transform, projection), player_transform) =
+        match (q_camera.get_single(), q_player.get_single()) {
+            (Ok(c), Ok(p)) => (c, p),
+            _ => return,
+        };
+
+    let player_pos = player_transform.translation.truncate();
+    let incoming: Vec<Vec2> = q_projectiles
+        .iter()
+        .filter(|(transform, groups)| {
+            let pos = transform.translation.truncate();
+            groups.filters.bits() & PLAYER_LAYER != 0
+                && pos.distance(player_pos) <= VOLLEY_RANGE
+                && transform.local_y().truncate().dot(player_pos - pos) > 0.0
+        })
+        .map(|(transform, _)| transform.translation.truncate())
+        .collect();
+
+    let volley = incoming.len() >= VOLLEY_SIZE;
+    let flash_on = (time.elapsed_seconds() * FLASH_RATE).sin() > 0.0;
+    for mut visibility in &mut q_warning {
+        *visibility = if volley && flash_on {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    if !volley || !flash_on {
+        return;
+    }
+
+    let view = view_rect(camera_transform, projection);
+    let centroid = incoming.iter().sum::<Vec2>() / incoming.len() as f32;
+    if view.contains(centroid) {
+        return;
+    }
+    let margin = EDGE_MARGIN * projection.scale;
+    let tip = edge_point(view.center(), view.half_size(), margin, centroid);
+    let dir = (centroid - view.center()).normalize_or_zero();
+    draw_arrow(
+        &mut gizmos,
+        tip,
+        dir,
+        MAX_ARROW_SIZE * 1.5 * projection.scale,
+        VOLLEY_COLOR,
+    );
+}
+
+pub struct ThreatIndicatorPlugin;
+
+impl Plugin for ThreatIndicatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Gaming), spawn_volley_warning)
+            .add_systems(
+                Update,
+                (
+                    draw_threat_indicators.after(move_camera),
+                    warn_incoming_volleys.after(move_camera),
+                )
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
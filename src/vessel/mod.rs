@@ -1,3 +1,5 @@
+pub mod section;
+pub mod sensor;
 pub mod ship;
 pub mod station;
 
@@ -17,7 +19,12 @@ pub struct GuardianVesselPlugin;
 
 impl Plugin for GuardianVesselPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ship::GuardianShipPlugin)
-            .add_event::<SpawnVessel>();
+        app.add_plugins((
+            ship::GuardianShipPlugin,
+            station::GuardianStationPlugin,
+            sensor::SensorPlugin,
+            section::HullSectionPlugin,
+        ))
+        .add_event::<SpawnVessel>();
     }
 }
@@ -0,0 +1,269 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Collider, CollisionGroups};
+
+use crate::{
+    enemy::boss::Boss,
+    projectile::ProjectileCollision,
+    turret::{wreck_turret, Turret, TurretWrecked},
+    ui::health::{DealDamage, Health},
+    vessel::ship::{move_ships, steer_ships},
+    GameAssets, GameState, ShipStats,
+};
+
+const SECTION_Z_OFFSET: Vec3 = Vec3::new(0.0, 0.0, 5.0);
+const DESTROYED_SECTION_COLOR: Color = Color::rgba(0.05, 0.05, 0.05, 0.6);
+/// Top speed left to a ship whose engines are destroyed, as a fraction of its max speed.
+const MIN_ENGINE_FACTOR: f32 = 0.25;
+/// Reload speed left to the mounts of a section just before it's destroyed.
+const MIN_MOUNT_FACTOR: f32 = 0.4;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum HullSectionKind {
+    Bow,
+    Midship,
+    Stern,
+    Engines,
+}
+
+#[derive(Clone)]
+pub struct HullSectionSpec {
+    pub kind: HullSectionKind,
+    /// Center of the section in the local space of the vessel.
+    pub offset: Vec2,
+    pub half_extents: Vec2,
+    /// Health of the section as a fraction of the vessel's max health,
+    /// the shares of a hull add up to 1.
+    pub health_share: f32,
+    /// The vessel sinks once a key section is destroyed, bosses excepted,
+    /// they go through all their phases.
+    pub key: bool,
+}
+
+impl HullSectionSpec {
+    fn contains(&self, point: Vec2) -> bool {
+        let d = (point - self.offset).abs();
+        d.x <= self.half_extents.x && d.y <= self.half_extents.y
+    }
+}
+
+/// Splits the hull of a vessel into sections that take hits instead of the vessel's own collider.
+/// Damage to a section still counts against the vessel's health, destroyed sections keep
+/// their collider so the hull has no holes.
+#[derive(Component, Clone)]
+pub struct HullSections {
+    pub sections: Vec<HullSectionSpec>,
+    /// Speed left to the ship as its engines take damage, from `MIN_ENGINE_FACTOR` to 1.
+    pub engine_factor: f32,
+}
+
+impl HullSections {
+    pub fn new(sections: Vec<HullSectionSpec>) -> Self {
+        Self {
+            sections,
+            engine_factor: 1.0,
+        }
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct HullSection {
+    pub vessel: Entity,
+    pub spec: HullSectionSpec,
+    pub destroyed: bool,
+}
+
+fn spawn_hull_sections(
+    mut commands: Commands,
+    q_vessels: Query<(Entity, &HullSections, &Health, &CollisionGroups), Added<Health>>,
+) {
+    for (vessel, hull_sections, health, groups) in &q_vessels {
+        for spec in &hull_sections.sections {
+            let entity = commands
+                .spawn((
+                    HullSection {
+                        vessel,
+                        spec: spec.clone(),
+                        destroyed: false,
+                    },
+                    Collider::cuboid(spec.half_extents.x, spec.half_extents.y),
+                    *groups,
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::NONE,
+                            custom_size: Some(spec.half_extents * 2.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                ))
+                .id();
+            commands.entity(entity).insert(Health::new(
+                entity,
+                health.max_health * spec.health_share,
+                1.0,
+            ));
+        }
+    }
+}
+
+fn reposition_hull_sections(
+    mut q_sections: Query<(&mut Transform, &HullSection)>,
+    q_transforms: Query<&Transform, Without<HullSection>>,
+) {
+    for (mut transform, section) in &mut q_sections {
+        let vessel_transform = match q_transforms.get(section.vessel) {
+            Ok(t) => t,
+            Err(_) => continue,
+        };
+        transform.translation = vessel_transform.translation
+            + vessel_transform
+                .rotation
+                .mul_vec3(section.spec.offset.extend(0.0))
+            + SECTION_Z_OFFSET;
+        transform.rotation = vessel_transform.rotation;
+    }
+}
+
+/// Sections only take the hit, the vessel's health is the sum of all the damage.
+fn forward_section_damage(
+    q_sections: Query<&HullSection>,
+    mut q_healths: Query<&mut Health, Without<HullSection>>,
+    mut ev_projectile_collision: EventReader<ProjectileCollision>,
+    mut ev_deal_damage: EventReader<DealDamage>,
+) {
+    let hits = ev_projectile_collision
+        .read()
+        .map(|ev| (ev.target, ev.projectile.damage))
+        .chain(ev_deal_damage.read().map(|ev| (ev.target, ev.amount)));
+    for (target, damage) in hits {
+        let section = match q_sections.get(target) {
+            Ok(s) => s,
+            Err(_) => continue,
+        };
+        if let Ok(mut health) = q_healths.get_mut(section.vessel) {
+            health.health -= damage;
+        }
+    }
+}
+
+/// Wreck the mounts of destroyed sections and sink vessels that lost a key section.
+fn destroy_hull_sections(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_sections: Query<(&mut HullSection, &Health, &mut Sprite)>,
+    mut q_vessels: Query<(&mut Health, Has<Boss>), Without<HullSection>>,
+    mut q_turrets: Query<(Entity, &mut Turret, &mut Handle<Image>), Without<TurretWrecked>>,
+) {
+    for (mut section, health, mut sprite) in &mut q_sections {
+        if section.destroyed || health.health > 0.0 {
+            continue;
+        }
+        section.destroyed = true;
+        sprite.color = DESTROYED_SECTION_COLOR;
+
+        for (turret_entity, mut turret, mut texture) in &mut q_turrets {
+            if turret.source != section.vessel || !section.spec.contains(turret.offset.truncate()) {
+                continue;
+            }
+            wreck_turret(
+                &mut commands,
+                &assets,
+                turret_entity,
+                &mut turret,
+                &mut texture,
+            );
+        }
+
+        if !section.spec.key {
+            continue;
+        }
+        // Bosses don't sink on a lost key section, they go through all their phases.
+        if let Ok((mut vessel_health, false)) = q_vessels.get_mut(section.vessel) {
+            vessel_health.health = 0.0;
+        }
+    }
+}
+
+/// Mounts reload slower the more damage their section took,
+/// including mounts fitted after the damage, e.g. by a boss phase.
+fn degrade_section_mounts(
+    q_sections: Query<(&HullSection, &Health)>,
+    mut q_turrets: Query<&mut Turret, Without<TurretWrecked>>,
+) {
+    for (section, health) in &q_sections {
+        let fraction = (health.health / health.max_health).clamp(0.0, 1.0);
+        let reload_factor = MIN_MOUNT_FACTOR + (1.0 - MIN_MOUNT_FACTOR) * fraction;
+        for mut turret in &mut q_turrets {
+            if turret.source != section.vessel || !section.spec.contains(turret.offset.truncate()) {
+                continue;
+            }
+            if turret.reload_factor != reload_factor {
+                turret.reload_factor = reload_factor;
+            }
+        }
+    }
+}
+
+fn update_engine_damage(
+    q_sections: Query<(&HullSection, &Health)>,
+    mut q_vessels: Query<(Entity, &mut HullSections)>,
+) {
+    for (vessel, mut hull_sections) in &mut q_vessels {
+        let (mut health, mut max_health) = (0.0, 0.0);
+        for (section, section_health) in &q_sections {
+            if section.vessel != vessel || section.spec.kind != HullSectionKind::Engines {
+                continue;
+            }
+            health += section_health.health.max(0.0);
+            max_health += section_health.max_health;
+        }
+        if max_health == 0.0 {
+            continue;
+        }
+
+        hull_sections.engine_factor =
+            MIN_ENGINE_FACTOR + (1.0 - MIN_ENGINE_FACTOR) * health / max_health;
+    }
+}
+
+fn limit_damaged_engines(mut q_ships: Query<(&mut ShipStats, &HullSections)>) {
+    for (mut ship_stats, hull_sections) in &mut q_ships {
+        let limit = (ship_stats.max_speed + ship_stats.boost) * hull_sections.engine_factor;
+        ship_stats.acceleration = ship_stats.acceleration.clamp_length_max(limit);
+    }
+}
+
+fn despawn_hull_sections(
+    mut commands: Commands,
+    q_transforms: Query<&Transform, Without<HullSection>>,
+    q_sections: Query<(Entity, &HullSection)>,
+) {
+    for (entity, section) in &q_sections {
+        if q_transforms.get(section.vessel).is_err() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub struct HullSectionPlugin;
+
+impl Plugin for HullSectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_hull_sections,
+                reposition_hull_sections
+                    .after(move_ships)
+                    .after(steer_ships),
+                forward_section_damage,
+                destroy_hull_sections,
+                degrade_section_mounts,
+                update_engine_damage,
+                limit_damaged_engines.after(move_ships),
+                despawn_hull_sections,
+            )
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::{enemy::Enemy, turret::Turret, world::weather::WeatherEffects, GameState};
+
+const UNDETECTED_COLOR: Color = Color::rgba(0.5, 0.5, 0.55, 0.3);
+
+pub struct SensorPlugin;
+
+impl Plugin for SensorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (update_detection, shade_undetected)
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
+
+/// How far a vessel can see other vessels in clear daylight.
+#[derive(Component, Clone)]
+pub struct Sensor {
+    pub radius: f32,
+}
+
+/// Whether the opposing side currently has this vessel on its sensors.
+/// Detection is shared, every vessel of a side knows what its allies see.
+#[derive(Component, Clone, Default)]
+pub struct Detected(pub bool);
+
+pub fn update_detection(
+    effects: Res<WeatherEffects>,
+    q_sensors: Query<(&Transform, &Sensor, Has<Enemy>)>,
+    mut q_detected: Query<(&Transform, &mut Detected, Has<Enemy>)>,
+) {
+    let sensors: Vec<(Vec2, f32, bool)> = q_sensors
+        .iter()
+        .map(|(transform, sensor, is_enemy)| {
+            (
+                transform.translation.truncate(),
+                sensor.radius * effects.detection_factor,
+                is_enemy,
+            )
+        })
+        .collect();
+
+    for (transform, mut detected, is_enemy) in &mut q_detected {
+        let pos = transform.translation.truncate();
+        let spotted = sensors.iter().any(|(sensor_pos, radius, sensor_enemy)| {
+            *sensor_enemy != is_enemy && sensor_pos.distance(pos) <= *radius
+        });
+        if detected.0 != spotted {
+            detected.0 = spotted;
+        }
+    }
+}
+
+/// Grey out enemies the player's side hasn't detected.
+#[allow(clippy::type_complexity)]
+fn shade_undetected(
+    mut q_enemies: Query<(&Detected, &mut Sprite), (With<Enemy>, Changed<Detected>)>,
+    q_detected: Query<&Detected, With<Enemy>>,
+    mut q_turrets: Query<(&Turret, &mut Sprite), Without<Enemy>>,
+) {
+    let color = |detected: bool| {
+        if detected {
+            Color::WHITE
+        } else {
+            UNDETECTED_COLOR
+        }
+    };
+
+    for (detected, mut sprite) in &mut q_enemies {
+        sprite.color = color(detected.0);
+    }
+
+    for (turret, mut sprite) in &mut q_turrets {
+        if let Ok(detected) = q_detected.get(turret.source) {
+            sprite.color = color(detected.0);
+        }
+    }
+}
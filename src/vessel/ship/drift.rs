@@ -0,0 +1,113 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use super::ShipStats;
+
+/// Fraction of `max_speed` a ship needs for its drift to count.
+const MIN_SPEED_FACTOR: f32 = 0.25;
+const MIN_BOOST_CHARGE: f32 = 0.25;
+const MAX_CHARGE: f32 = 2.0;
+/// Boost at full charge as a fraction of `max_speed`.
+const BOOST_FACTOR: f32 = 0.5;
+/// Boost lost per second as a fraction of `max_speed`.
+const BOOST_DECAY: f32 = 0.5;
+/// Seconds of continuous drifting needed to raise the combo by one.
+const COMBO_STEP: f32 = 1.5;
+/// Seconds after a drift in which the next drift keeps the combo.
+const COMBO_WINDOW: f32 = 2.0;
+const MAX_COMBO: u32 = 8;
+const SCORE_PER_CHARGE: f32 = 100.0;
+
+#[derive(Component, Clone)]
+pub struct DriftState {
+    pub drifting: bool,
+    pub duration: f32,
+    /// Drift time weighted by how sharp the drift was, released as a boost.
+    pub charge: f32,
+    pub combo: u32,
+    pub combo_timer: Timer,
+    pub score: f32,
+}
+
+impl Default for DriftState {
+    fn default() -> Self {
+        Self {
+            drifting: false,
+            duration: 0.0,
+            charge: 0.0,
+            combo: 1,
+            combo_timer: Timer::from_seconds(COMBO_WINDOW, TimerMode::Once),
+            score: 0.0,
+        }
+    }
+}
+
+impl DriftState {
+    fn combo_multiplier(&self) -> f32 {
+        1.0 + 0.25 * (self.combo - 1) as f32
+    }
+}
+
+/// How sideways the ship is moving, 0 when moving along its heading
+/// and 1 at 90 degrees or more.
+pub fn drift_sharpness(velocity: Vec2, heading: Vec2) -> f32 {
+    if velocity == Vec2::ZERO || heading == Vec2::ZERO {
+        return 0.0;
+    }
+    (velocity.angle_between(heading).abs() / FRAC_PI_2).min(1.0)
+}
+
+fn release_drift(transform: &Transform, ship_stats: &mut ShipStats, drift: &mut DriftState) {
+    drift.drifting = false;
+    drift.duration = 0.0;
+
+    let charge = drift.charge.min(MAX_CHARGE);
+    drift.charge = 0.0;
+    if charge < MIN_BOOST_CHARGE {
+        return;
+    }
+
+    ship_stats.boost =
+        ship_stats.max_speed * BOOST_FACTOR * charge / MAX_CHARGE * drift.combo_multiplier();
+    let speed = ship_stats.acceleration.length() + ship_stats.boost;
+    ship_stats.acceleration = transform.local_y().truncate() * speed;
+    drift.combo_timer.reset();
+}
+
+pub fn update_drifts(
+    time: Res<Time>,
+    mut ships: Query<(&Transform, &mut ShipStats, &mut DriftState)>,
+) {
+    for (transform, mut ship_stats, mut drift) in &mut ships {
+        let max_speed = ship_stats.max_speed;
+        ship_stats.boost =
+            (ship_stats.boost - max_speed * BOOST_DECAY * time.delta_seconds()).max(0.0);
+
+        let fast_enough = ship_stats.acceleration.length() >= max_speed * MIN_SPEED_FACTOR;
+        if ship_stats.traction != 0.0 || !fast_enough {
+            if drift.drifting {
+                release_drift(transform, &mut ship_stats, &mut drift);
+            }
+
+            drift.combo_timer.tick(time.delta());
+            if drift.combo_timer.finished() {
+                drift.combo = 1;
+            }
+            continue;
+        }
+
+        let sharpness = drift_sharpness(ship_stats.acceleration, transform.local_y().truncate());
+        let charge = sharpness * time.delta_seconds();
+
+        drift.drifting = true;
+        drift.duration += time.delta_seconds();
+        drift.charge += charge;
+        drift.score += charge * SCORE_PER_CHARGE * drift.combo as f32;
+
+        let combo = (drift.duration / COMBO_STEP) as u32;
+        if combo > 0 && drift.duration - time.delta_seconds() < combo as f32 * COMBO_STEP {
+            drift.combo = (drift.combo + 1).min(MAX_COMBO);
+        }
+    }
+}
@@ -1,7 +1,28 @@
+pub mod drift;
+pub mod route;
+
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::{Collider, CollisionGroups, Group};
 
-use crate::{turret::TurretStats, GameAssets, GameState};
+use crate::{
+    collision::TORPEDO_LAYER,
+    turret::TurretStats,
+    vessel::{
+        section::{HullSectionKind, HullSectionSpec, HullSections},
+        sensor::{Detected, Sensor},
+    },
+    world::currents::FlowField,
+    GameAssets, GameState,
+};
+
+use drift::{update_drifts, DriftState};
+use route::follow_routes;
+
+/// Angle in radians at which AI controlled ships steer at full rate.
+const AI_FULL_STEER_ANGLE: f32 = 0.5;
+const AI_TRACTION: f32 = 5.0;
 
 pub struct GuardianShipPlugin;
 
@@ -9,7 +30,14 @@ impl Plugin for GuardianShipPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (move_ships, steer_ships).run_if(in_state(GameState::Gaming)),
+            (
+                update_dash.before(move_ships),
+                follow_routes.before(move_ships),
+                move_ships,
+                update_drifts.after(move_ships),
+                steer_ships,
+            )
+                .run_if(in_state(GameState::Gaming)),
         );
     }
 }
@@ -26,16 +54,61 @@ pub struct ShipStats {
 
     pub min_speed: f32,
     pub max_speed: f32,
+    /// Extra speed on top of `max_speed`, e.g. from a drift boost.
+    pub boost: f32,
 
     pub dash: bool,
 }
 
+/// Energy pool that limits dashing. Ships request a dash through `requested`,
+/// `ShipStats.dash` is only set while the ship can actually afford it.
+#[derive(Component, Clone)]
+pub struct DashEnergy {
+    pub energy: f32,
+    pub max_energy: f32,
+    /// Energy regenerated per second while not dashing.
+    pub regen: f32,
+    /// Energy drained per second while dashing.
+    pub drain: f32,
+    /// Minimum energy needed to start a dash, paid up front.
+    pub activation_cost: f32,
+    pub cooldown_timer: Timer,
+    pub requested: bool,
+}
+
+impl Default for DashEnergy {
+    fn default() -> Self {
+        let mut cooldown_timer = Timer::from_seconds(0.75, TimerMode::Once);
+        cooldown_timer.tick(cooldown_timer.duration());
+        Self {
+            energy: 100.0,
+            max_energy: 100.0,
+            regen: 20.0,
+            drain: 40.0,
+            activation_cost: 20.0,
+            cooldown_timer,
+            requested: false,
+        }
+    }
+}
+
+impl DashEnergy {
+    /// Whether a dash could be started right now.
+    pub fn ready(&self) -> bool {
+        self.cooldown_timer.finished() && self.energy >= self.activation_cost
+    }
+}
+
 #[derive(Bundle)]
 pub struct ShipVessel {
     collider: Collider,
     collision_groups: CollisionGroups,
     ship_stats: ShipStats,
+    dash_energy: DashEnergy,
+    drift_state: DriftState,
     turret_stats: TurretStats,
+    sensor: Sensor,
+    detected: Detected,
     sprite: SpriteBundle,
 }
 
@@ -45,14 +118,13 @@ pub struct SmallShip1 {
 }
 
 impl SmallShip1 {
-    #[allow(dead_code)]
     pub fn new(assets: &Res<GameAssets>, collision_layer: u32, collision_mask: u32) -> Self {
         Self {
             ship_vessel: ShipVessel {
                 collider: Collider::capsule(Vec2::new(0.0, -20.0), Vec2::new(0.0, 20.0), 15.0),
                 collision_groups: CollisionGroups::new(
                     Group::from_bits(collision_layer).unwrap(),
-                    Group::from_bits(collision_mask).unwrap(),
+                    Group::from_bits(collision_mask | TORPEDO_LAYER).unwrap(),
                 ),
                 ship_stats: ShipStats {
                     delta_steering: 4.0,
@@ -61,9 +133,13 @@ impl SmallShip1 {
                     max_speed: 500.0,
                     ..default()
                 },
+                dash_energy: DashEnergy::default(),
+                drift_state: DriftState::default(),
                 turret_stats: TurretStats {
                     turret_offsets: vec![Vec2::ZERO],
                 },
+                sensor: Sensor { radius: 1500.0 },
+                detected: Detected::default(),
                 sprite: SpriteBundle {
                     texture: assets.small_ship_1.clone(),
                     ..default()
@@ -76,6 +152,7 @@ impl SmallShip1 {
 #[derive(Bundle)]
 pub struct BigShip {
     ship_vessel: ShipVessel,
+    hull_sections: HullSections,
 }
 
 impl BigShip {
@@ -85,7 +162,7 @@ impl BigShip {
                 collider: Collider::capsule(Vec2::new(0.0, -90.0), Vec2::new(0.0, 90.0), 40.0),
                 collision_groups: CollisionGroups::new(
                     Group::from_bits(collision_layer).unwrap(),
-                    Group::from_bits(collision_mask).unwrap(),
+                    Group::from_bits(collision_mask | TORPEDO_LAYER).unwrap(),
                 ),
                 ship_stats: ShipStats {
                     delta_steering: 1.5,
@@ -95,6 +172,8 @@ impl BigShip {
                     max_speed: 1000.0,
                     ..default()
                 },
+                dash_energy: DashEnergy::default(),
+                drift_state: DriftState::default(),
                 turret_stats: TurretStats {
                     turret_offsets: vec![
                         Vec2::new(-16.0, -16.0),
@@ -105,35 +184,156 @@ impl BigShip {
                         Vec2::new(16.0, 48.0),
                     ],
                 },
+                sensor: Sensor { radius: 2500.0 },
+                detected: Detected::default(),
                 sprite: SpriteBundle {
                     texture: assets.boat.clone(),
                     ..default()
                 },
             },
+            hull_sections: HullSections::new(vec![
+                HullSectionSpec {
+                    kind: HullSectionKind::Bow,
+                    offset: Vec2::new(0.0, 95.0),
+                    half_extents: Vec2::new(32.0, 35.0),
+                    health_share: 0.25,
+                    key: true,
+                },
+                HullSectionSpec {
+                    kind: HullSectionKind::Midship,
+                    offset: Vec2::new(0.0, 30.0),
+                    half_extents: Vec2::new(40.0, 30.0),
+                    health_share: 0.35,
+                    key: true,
+                },
+                HullSectionSpec {
+                    kind: HullSectionKind::Stern,
+                    offset: Vec2::new(0.0, -35.0),
+                    half_extents: Vec2::new(40.0, 35.0),
+                    health_share: 0.2,
+                    key: false,
+                },
+                HullSectionSpec {
+                    kind: HullSectionKind::Engines,
+                    offset: Vec2::new(0.0, -100.0),
+                    half_extents: Vec2::new(30.0, 30.0),
+                    health_share: 0.2,
+                    key: false,
+                },
+            ]),
+        }
+    }
+}
+
+/// Slow cargo ship without weapons, escorted in convoy missions.
+#[derive(Bundle)]
+pub struct MerchantShip {
+    ship_vessel: ShipVessel,
+}
+
+impl MerchantShip {
+    pub fn new(assets: &Res<GameAssets>, collision_layer: u32, collision_mask: u32) -> Self {
+        Self {
+            ship_vessel: ShipVessel {
+                collider: Collider::capsule(Vec2::new(0.0, -20.0), Vec2::new(0.0, 20.0), 14.0),
+                collision_groups: CollisionGroups::new(
+                    Group::from_bits(collision_layer).unwrap(),
+                    Group::from_bits(collision_mask | TORPEDO_LAYER).unwrap(),
+                ),
+                ship_stats: ShipStats {
+                    delta_steering: 2.0,
+                    delta_speed: 120.0,
+                    drag: 0.5,
+                    min_speed: 0.0,
+                    max_speed: 180.0,
+                    ..default()
+                },
+                dash_energy: DashEnergy::default(),
+                drift_state: DriftState::default(),
+                turret_stats: TurretStats {
+                    turret_offsets: vec![Vec2::ZERO],
+                },
+                sensor: Sensor { radius: 1200.0 },
+                detected: Detected::default(),
+                sprite: SpriteBundle {
+                    texture: assets.ship.clone(),
+                    ..default()
+                },
+            },
         }
     }
 }
 
-pub fn move_ships(time: Res<Time>, mut ships: Query<(&mut Transform, &mut ShipStats)>) {
-    for (mut transform, mut ship_stats) in &mut ships {
+/// Turn and accelerate an AI controlled ship towards `destination`,
+/// easing off the throttle in sharp turns so it doesn't overshoot.
+pub fn steer_towards(
+    transform: &Transform,
+    ship_stats: &mut ShipStats,
+    destination: Vec2,
+    dt: f32,
+) {
+    let heading = transform.local_y().truncate();
+    let angle = heading.angle_between(destination - transform.translation.truncate());
+    ship_stats.current_steering_direction = (angle / AI_FULL_STEER_ANGLE).clamp(-1.0, 1.0);
+    ship_stats.traction = AI_TRACTION;
+
+    let throttle = (1.0 - angle.abs() / PI).max(0.2);
+    let speed = ship_stats.delta_speed;
+    ship_stats.acceleration += heading * speed * throttle * dt;
+}
+
+pub fn update_dash(time: Res<Time>, mut ships: Query<(&mut ShipStats, &mut DashEnergy)>) {
+    for (mut ship_stats, mut dash_energy) in &mut ships {
+        dash_energy.cooldown_timer.tick(time.delta());
+
+        if ship_stats.dash {
+            dash_energy.energy -= dash_energy.drain * time.delta_seconds();
+            if !dash_energy.requested || dash_energy.energy <= 0.0 {
+                dash_energy.energy = dash_energy.energy.max(0.0);
+                dash_energy.cooldown_timer.reset();
+                ship_stats.dash = false;
+            }
+            continue;
+        }
+
+        dash_energy.energy = (dash_energy.energy + dash_energy.regen * time.delta_seconds())
+            .min(dash_energy.max_energy);
+
+        if dash_energy.requested && dash_energy.ready() {
+            dash_energy.energy -= dash_energy.activation_cost;
+            ship_stats.dash = true;
+        }
+    }
+}
+
+pub fn move_ships(
+    time: Res<Time>,
+    flow_field: Res<FlowField>,
+    mut ships: Query<(&mut Transform, &mut ShipStats, Option<&HullSections>)>,
+) {
+    for (mut transform, mut ship_stats, hull_sections) in &mut ships {
         if ship_stats.dash {
+            let engine_factor = hull_sections.map_or(1.0, |h| h.engine_factor);
+            let speed = ship_stats.max_speed * engine_factor;
             let dir = transform.local_y();
-            transform.translation += dir * ship_stats.max_speed * 2.0 * time.delta_seconds();
-            ship_stats.acceleration = dir.truncate() * ship_stats.max_speed;
+            transform.translation += dir * speed * 2.0 * time.delta_seconds();
+            ship_stats.acceleration = dir.truncate() * speed;
             continue;
         }
 
+        ship_stats.acceleration +=
+            flow_field.sample(transform.translation.truncate()) * time.delta_seconds();
         transform.translation += ship_stats.acceleration.extend(0.0) * time.delta_seconds();
 
-        let drag = 1.0 - ship_stats.drag * time.delta_seconds();
+        let drag = 1.0 - (ship_stats.drag + flow_field.wave_drag) * time.delta_seconds();
         ship_stats.acceleration *= drag;
         ship_stats.acceleration = ship_stats
             .acceleration
-            .clamp_length(0.0, ship_stats.max_speed);
+            .clamp_length(0.0, ship_stats.max_speed + ship_stats.boost);
 
         let speed = ship_stats.acceleration.length();
         if speed == 0.0 {
-            return;
+            continue;
         }
 
         let traction = ship_stats.traction * time.delta_seconds();
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::ShipStats;
+
+use super::steer_towards;
+
+/// Sails an AI controlled ship along a list of waypoints, stopping at the last one.
+#[derive(Component, Clone)]
+pub struct RouteFollower {
+    pub waypoints: Vec<Vec2>,
+    pub current: usize,
+    /// Distance at which a waypoint counts as reached.
+    pub arrive_radius: f32,
+}
+
+impl RouteFollower {
+    pub fn new(waypoints: Vec<Vec2>) -> Self {
+        Self {
+            waypoints,
+            current: 0,
+            arrive_radius: 150.0,
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        self.current >= self.waypoints.len()
+    }
+}
+
+pub fn follow_routes(
+    time: Res<Time>,
+    mut q_ships: Query<(&Transform, &mut ShipStats, &mut RouteFollower)>,
+) {
+    for (transform, mut ship_stats, mut route) in &mut q_ships {
+        if route.finished() {
+            ship_stats.current_steering_direction = 0.0;
+            continue;
+        }
+
+        let waypoint = route.waypoints[route.current];
+        if transform.translation.truncate().distance(waypoint) <= route.arrive_radius {
+            route.current += 1;
+            continue;
+        }
+
+        steer_towards(transform, &mut ship_stats, waypoint, time.delta_seconds());
+    }
+}
@@ -1,7 +1,19 @@
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::{Collider, CollisionGroups, Group};
 
-use crate::{turret::TurretStats, GameAssets, GameState};
+use crate::{
+    collision::TORPEDO_LAYER,
+    enemy::Enemy,
+    player::Player,
+    turret::TurretStats,
+    vessel::sensor::{Detected, Sensor},
+    GameAssets, GameState,
+};
+
+/// Angle in radians below which a station stops turning towards its target.
+const AIM_TOLERANCE: f32 = 0.05;
 
 pub struct GuardianStationPlugin;
 
@@ -9,15 +21,30 @@ impl Plugin for GuardianStationPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (rotate_stations).run_if(in_state(GameState::Gaming)),
+            (aim_stations, rotate_stations)
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
         );
     }
 }
 
-#[derive(Component, Clone, Default)]
+#[derive(Component, Clone)]
 pub struct StationStats {
     pub delta_steering: f32,
     pub current_steering_direction: f32,
+    /// Local direction the station turns towards its target,
+    /// the side where most of its mounts can fire.
+    pub facing: Vec2,
+}
+
+impl Default for StationStats {
+    fn default() -> Self {
+        Self {
+            delta_steering: 0.0,
+            current_steering_direction: 0.0,
+            facing: Vec2::Y,
+        }
+    }
 }
 
 impl StationStats {}
@@ -28,6 +55,8 @@ pub struct StationVessel {
     collision_groups: CollisionGroups,
     station_stats: StationStats,
     turret_stats: TurretStats,
+    sensor: Sensor,
+    detected: Detected,
     sprite: SpriteBundle,
 }
 
@@ -43,7 +72,7 @@ impl SmallStation1 {
                 collider: Collider::cuboid(48.0, 48.0),
                 collision_groups: CollisionGroups::new(
                     Group::from_bits(collision_layer).unwrap(),
-                    Group::from_bits(collision_mask).unwrap(),
+                    Group::from_bits(collision_mask | TORPEDO_LAYER).unwrap(),
                 ),
                 station_stats: StationStats {
                     delta_steering: 4.0,
@@ -52,6 +81,8 @@ impl SmallStation1 {
                 turret_stats: TurretStats {
                     turret_offsets: vec![Vec2::ZERO],
                 },
+                sensor: Sensor { radius: 2000.0 },
+                detected: Detected::default(),
                 sprite: SpriteBundle {
                     texture: assets.station.clone(),
                     ..default()
@@ -61,6 +92,115 @@ impl SmallStation1 {
     }
 }
 
+/// Square station with a mount on every corner.
+#[derive(Bundle)]
+pub struct SmallStation2 {
+    station_vessel: StationVessel,
+}
+
+impl SmallStation2 {
+    pub fn new(assets: &Res<GameAssets>, collision_layer: u32, collision_mask: u32) -> Self {
+        Self {
+            station_vessel: StationVessel {
+                collider: Collider::cuboid(96.0, 96.0),
+                collision_groups: CollisionGroups::new(
+                    Group::from_bits(collision_layer).unwrap(),
+                    Group::from_bits(collision_mask | TORPEDO_LAYER).unwrap(),
+                ),
+                station_stats: StationStats {
+                    delta_steering: 1.0,
+                    ..default()
+                },
+                turret_stats: TurretStats {
+                    turret_offsets: vec![
+                        Vec2::new(-64.0, -64.0),
+                        Vec2::new(64.0, -64.0),
+                        Vec2::new(-64.0, 64.0),
+                        Vec2::new(64.0, 64.0),
+                    ],
+                },
+                sensor: Sensor { radius: 2500.0 },
+                detected: Detected::default(),
+                sprite: SpriteBundle {
+                    texture: assets.small_station_2.clone(),
+                    ..default()
+                },
+            },
+        }
+    }
+}
+
+/// Long station that turns its broadside towards targets.
+#[derive(Bundle)]
+pub struct SmallStation3 {
+    station_vessel: StationVessel,
+}
+
+impl SmallStation3 {
+    pub fn new(assets: &Res<GameAssets>, collision_layer: u32, collision_mask: u32) -> Self {
+        Self {
+            station_vessel: StationVessel {
+                collider: Collider::cuboid(144.0, 96.0),
+                collision_groups: CollisionGroups::new(
+                    Group::from_bits(collision_layer).unwrap(),
+                    Group::from_bits(collision_mask | TORPEDO_LAYER).unwrap(),
+                ),
+                station_stats: StationStats {
+                    delta_steering: 0.5,
+                    facing: Vec2::Y,
+                    ..default()
+                },
+                turret_stats: TurretStats {
+                    turret_offsets: vec![
+                        Vec2::new(-112.0, 48.0),
+                        Vec2::new(0.0, 64.0),
+                        Vec2::new(112.0, 48.0),
+                        Vec2::new(-112.0, -48.0),
+                        Vec2::new(112.0, -48.0),
+                    ],
+                },
+                sensor: Sensor { radius: 3000.0 },
+                detected: Detected::default(),
+                sprite: SpriteBundle {
+                    texture: assets.small_station_3.clone(),
+                    ..default()
+                },
+            },
+        }
+    }
+}
+
+/// Turn enemy stations towards the player once their side has detected it.
+fn aim_stations(
+    mut q_stations: Query<(&Transform, &mut StationStats), With<Enemy>>,
+    q_player: Query<(&Transform, &Detected), With<Player>>,
+) {
+    let (player_transform, player_detected) = match q_player.get_single() {
+        Ok(p) => p,
+        Err(_) => return,
+    };
+
+    for (transform, mut station_stats) in &mut q_stations {
+        if !player_detected.0 {
+            station_stats.current_steering_direction = 0.0;
+            continue;
+        }
+
+        let to_target = (player_transform.translation - transform.translation).truncate();
+        let facing = transform
+            .rotation
+            .mul_vec3(station_stats.facing.extend(0.0))
+            .truncate();
+        let angle = facing.angle_between(to_target);
+        station_stats.current_steering_direction = if angle.abs() < AIM_TOLERANCE {
+            0.0
+        } else {
+            // Ease in close to the target angle so stations don't overshoot.
+            (angle / (PI / 8.0)).clamp(-1.0, 1.0)
+        };
+    }
+}
+
 pub fn rotate_stations(time: Res<Time>, mut q_stations: Query<(&mut Transform, &StationStats)>) {
     for (mut transform, station_stats) in &mut q_stations {
         let rotation = station_stats.current_steering_direction
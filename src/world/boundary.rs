@@ -0,0 +1,208 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::projectile::Projectile;
+use crate::turret::Turret;
+use crate::ui::health::Health;
+use crate::vessel::section::HullSection;
+use crate::vessel::ship::move_ships;
+use crate::world::camera::move_camera;
+use crate::{GameState, ShipStats};
+
+const WALL_Z: f32 = -40.0;
+const WALL_SEGMENTS: usize = 256;
+const WALL_COLOR: Color = Color::rgba(0.25, 0.27, 0.32, 0.75);
+
+/// Circular play area around the origin. Ships outside of it get pushed back
+/// and take damage that grows the longer they stay out.
+#[derive(Resource, Clone)]
+pub struct ArenaBoundary {
+    pub radius: f32,
+    /// Width of the storm wall drawn just outside the radius.
+    pub wall_width: f32,
+    /// Acceleration towards the arena per unit of distance outside.
+    pub push_strength: f32,
+    /// Damage per second when just crossing the boundary.
+    pub damage: f32,
+    /// How much the damage grows per second spent outside.
+    pub damage_growth: f32,
+    /// Projectiles this far outside are despawned.
+    pub projectile_margin: f32,
+}
+
+impl Default for ArenaBoundary {
+    fn default() -> Self {
+        Self {
+            radius: 7500.0,
+            wall_width: 400.0,
+            push_strength: 4.0,
+            damage: 20.0,
+            damage_growth: 1.0,
+            projectile_margin: 500.0,
+        }
+    }
+}
+
+impl ArenaBoundary {
+    pub fn contains(&self, position: Vec2) -> bool {
+        position.length() <= self.radius
+    }
+
+    /// Move `position` inside the arena, keeping `margin` from the boundary.
+    pub fn clamp_inside(&self, position: Vec2, margin: f32) -> Vec2 {
+        position.clamp_length_max((self.radius - margin).max(0.0))
+    }
+}
+
+/// Seconds a vessel has spent outside the arena in a row.
+#[derive(Component, Default)]
+struct OutOfBounds(f32);
+
+#[derive(Component)]
+struct StormWall;
+
+fn ring_mesh(inner_radius: f32, outer_radius: f32) -> Mesh {
+    let mut positions = Vec::with_capacity(WALL_SEGMENTS * 2);
+    let mut uvs = Vec::with_capacity(WALL_SEGMENTS * 2);
+    let mut indices = Vec::with_capacity(WALL_SEGMENTS * 6);
+
+    for i in 0..WALL_SEGMENTS {
+        let t = i as f32 / WALL_SEGMENTS as f32;
+        let dir = Vec2::from_angle(t * TAU);
+        positions.push((dir * inner_radius).extend(0.0).to_array());
+        positions.push((dir * outer_radius).extend(0.0).to_array());
+        uvs.push([t, 0.0]);
+        uvs.push([t, 1.0]);
+
+        let inner = (i * 2) as u32;
+        let next = (((i + 1) % WALL_SEGMENTS) * 2) as u32;
+        indices.extend([inner, inner + 1, next, next, inner + 1, next + 1]);
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_NORMAL,
+        vec![[0.0, 0.0, 1.0]; positions.len()],
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+fn spawn_storm_wall(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    boundary: Res<ArenaBoundary>,
+) {
+    commands.spawn((
+        StormWall,
+        MaterialMesh2dBundle {
+            mesh: meshes
+                .add(ring_mesh(
+                    boundary.radius,
+                    boundary.radius + boundary.wall_width,
+                ))
+                .into(),
+            material: materials.add(ColorMaterial::from(WALL_COLOR)),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.0, WALL_Z)),
+            ..default()
+        },
+    ));
+}
+
+/// Let the storm wall flicker a bit so it doesn't read as solid terrain.
+fn animate_storm_wall(
+    time: Res<Time>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    q_wall: Query<&Handle<ColorMaterial>, With<StormWall>>,
+) {
+    let alpha = WALL_COLOR.a() * (0.85 + 0.15 * (time.elapsed_seconds() * 3.0).sin());
+    for handle in &q_wall {
+        if let Some(material) = materials.get_mut(handle) {
+            material.color.set_a(alpha);
+        }
+    }
+}
+
+fn push_back_ships(
+    time: Res<Time>,
+    boundary: Res<ArenaBoundary>,
+    mut q_ships: Query<(&Transform, &mut ShipStats)>,
+) {
+    for (transform, mut ship_stats) in &mut q_ships {
+        let position = transform.translation.truncate();
+        let outside = position.length() - boundary.radius;
+        if outside <= 0.0 {
+            continue;
+        }
+
+        ship_stats.acceleration -=
+            position.normalize() * outside * boundary.push_strength * time.delta_seconds();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn damage_out_of_bounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    boundary: Res<ArenaBoundary>,
+    mut q_vessels: Query<
+        (Entity, &Transform, &mut Health, Option<&mut OutOfBounds>),
+        (Without<Turret>, Without<HullSection>),
+    >,
+) {
+    for (entity, transform, mut health, out_of_bounds) in &mut q_vessels {
+        let inside = boundary.contains(transform.translation.truncate());
+        match (inside, out_of_bounds) {
+            (true, Some(_)) => {
+                commands.entity(entity).remove::<OutOfBounds>();
+            }
+            (false, Some(mut out_of_bounds)) => {
+                out_of_bounds.0 += time.delta_seconds();
+                let damage = boundary.damage * (1.0 + boundary.damage_growth * out_of_bounds.0);
+                health.health -= damage * time.delta_seconds();
+            }
+            (false, None) => {
+                commands.entity(entity).insert(OutOfBounds::default());
+            }
+            (true, None) => {}
+        }
+    }
+}
+
+/// Disabled projectiles get despawned by the projectile plugin like expired ones.
+fn despawn_stray_projectiles(
+    boundary: Res<ArenaBoundary>,
+    mut q_projectiles: Query<(&Transform, &mut Projectile)>,
+) {
+    let limit = boundary.radius + boundary.projectile_margin;
+    for (transform, mut projectile) in &mut q_projectiles {
+        if transform.translation.truncate().length() > limit {
+            projectile.disabled = true;
+        }
+    }
+}
+
+pub struct BoundaryPlugin;
+
+impl Plugin for BoundaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ArenaBoundary>()
+            .add_systems(OnEnter(GameState::Gaming), spawn_storm_wall)
+            .add_systems(
+                Update,
+                (
+                    push_back_ships.after(move_ships).before(move_camera),
+                    damage_out_of_bounds,
+                    despawn_stray_projectiles,
+                    animate_storm_wall,
+                )
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
@@ -2,62 +2,313 @@ use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
 use bevy::window::{PrimaryWindow, WindowMode};
 
-use crate::player::input::{fetch_mouse_world_coords, MouseWorldCoords};
+use crate::player::input::action::{update_actions, Action, ActionInput};
+use crate::player::input::{fetch_aim_world_coords, right_stick, AimWorldCoords, InputDevice};
 use crate::player::Player;
+use crate::projectile::{ProjectileCollision, ProjectileDespawn, ProjectileType};
 use crate::vessel::ship::move_ships;
-use crate::GameState;
+use crate::{GameState, ShipStats};
+
+/// Damage of a hit on the player that adds half the maximum trauma.
+const HIT_TRAUMA_DAMAGE: f32 = 40.0;
+const EXPLOSION_TRAUMA: f32 = 0.15;
+/// Explosions further away from the player than this don't shake the camera.
+const EXPLOSION_SHAKE_RANGE: f32 = 800.0;
+const DASH_TRAUMA: f32 = 0.25;
 
 pub struct GuardianCameraPlugin;
 
 impl Plugin for GuardianCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            move_camera
-                .after(move_ships)
-                .after(fetch_mouse_world_coords)
-                .run_if(in_state(GameState::Gaming)),
-        )
-        .add_systems(OnEnter(GameState::Gaming), (spawn_camera,))
-        .add_systems(Update, toggle_full_screen);
+        app.init_resource::<CameraSettings>()
+            .add_event::<CameraShake>()
+            .add_systems(
+                Update,
+                (
+                    (shake_on_hits, shake_on_explosions, shake_on_dash),
+                    ease_zoom,
+                    update_look_ahead,
+                    move_camera,
+                )
+                    .chain()
+                    .after(move_ships)
+                    .after(fetch_aim_world_coords)
+                    .run_if(in_state(GameState::Gaming)),
+            )
+            .add_systems(OnEnter(GameState::Gaming), (spawn_camera,))
+            .add_systems(Update, toggle_full_screen.after(update_actions));
     }
 }
 
 #[derive(Component)]
 pub struct MainCamera;
 
+#[derive(Resource, Clone)]
+pub struct CameraSettings {
+    /// How fast the camera catches up with its target, higher is snappier.
+    pub follow_stiffness: f32,
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    /// Factor the zoom changes by per scroll step.
+    pub zoom_step: f32,
+    /// How fast the zoom eases towards its target.
+    pub zoom_speed: f32,
+    /// 0 disables screen shake, 1 is full strength.
+    pub shake_intensity: f32,
+    /// Offset in pixels at full trauma.
+    pub max_shake_offset: f32,
+    /// Rotation in radians at full trauma.
+    pub max_shake_angle: f32,
+    /// Trauma lost per second.
+    pub trauma_decay: f32,
+    /// Seconds of travel the camera leads the player by, 0 disables look-ahead.
+    pub look_ahead_time: f32,
+    /// Maximum look-ahead offset in pixels.
+    pub max_look_ahead: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            follow_stiffness: 8.0,
+            min_zoom: 1.0,
+            max_zoom: 10.0,
+            zoom_step: 1.2,
+            zoom_speed: 10.0,
+            shake_intensity: 1.0,
+            max_shake_offset: 20.0,
+            max_shake_angle: 0.03,
+            trauma_decay: 1.5,
+            look_ahead_time: 0.3,
+            max_look_ahead: 250.0,
+        }
+    }
+}
+
+/// Adds trauma to the camera, shake grows with the square of the trauma.
+#[derive(Event)]
+pub struct CameraShake {
+    pub trauma: f32,
+}
+
+/// The camera's state without shake applied.
+#[derive(Component)]
+pub struct CameraRig {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub target_zoom: f32,
+    pub trauma: f32,
+    /// Offset in the direction of travel, added on top of the aim offset.
+    pub look_ahead: Vec2,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            target_zoom: 1.0,
+            trauma: 0.0,
+            look_ahead: Vec2::ZERO,
+        }
+    }
+}
+
+impl CameraRig {
+    /// Step the zoom target one scroll step in or out.
+    pub fn zoom(&mut self, settings: &CameraSettings, direction: f32) {
+        let factor = settings.zoom_step.powf(-direction.signum());
+        self.target_zoom = (self.target_zoom * factor).clamp(settings.min_zoom, settings.max_zoom);
+    }
+}
+
 fn spawn_camera(mut commands: Commands) {
     let mut camera = Camera2dBundle::default();
     camera.projection.scaling_mode = ScalingMode::FixedVertical(750.0);
-    commands.spawn((MainCamera, camera));
+    commands.spawn((MainCamera, CameraRig::default(), camera));
+}
+
+/// Critically damped spring towards `target`, the same approximation
+/// most engines use for their "smooth damp" helpers.
+fn smooth_damp(current: Vec2, target: Vec2, velocity: &mut Vec2, stiffness: f32, dt: f32) -> Vec2 {
+    let x = stiffness * dt;
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + stiffness * change) * dt;
+    *velocity = (*velocity - stiffness * temp) * decay;
+    target + (change + temp) * decay
+}
+
+/// Cheap smooth noise in -1..1, good enough for shaking a camera.
+fn shake_noise(t: f32, seed: f32) -> f32 {
+    ((t * 23.0 + seed).sin() + (t * 37.0 + seed * 1.7).sin() * 0.5) / 1.5
+}
+
+fn ease_zoom(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut q_camera: Query<(&mut OrthographicProjection, &CameraRig), With<MainCamera>>,
+) {
+    let (mut projection, rig) = match q_camera.get_single_mut() {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let t = 1.0 - (-settings.zoom_speed * time.delta_seconds()).exp();
+    let scale = projection.scale + (rig.target_zoom - projection.scale) * t;
+    if (scale - rig.target_zoom).abs() < 0.001 {
+        projection.scale = rig.target_zoom;
+    } else {
+        projection.scale = scale;
+    }
+}
+
+fn update_look_ahead(
+    settings: Res<CameraSettings>,
+    device: Res<InputDevice>,
+    axes: Res<Axis<GamepadAxis>>,
+    q_player: Query<&ShipStats, With<Player>>,
+    mut q_camera: Query<(&mut CameraRig, &OrthographicProjection), With<MainCamera>>,
+) {
+    let (ship_stats, (mut rig, projection)) =
+        match (q_player.get_single(), q_camera.get_single_mut()) {
+            (Ok(s), Ok(c)) => (s, c),
+            _ => return,
+        };
+
+    // Aiming with the stick already moves the camera, leading on top of that is too much.
+    let aiming_with_stick = device
+        .gamepad()
+        .is_some_and(|gamepad| right_stick(&axes, gamepad) != Vec2::ZERO);
+    if aiming_with_stick {
+        rig.look_ahead = Vec2::ZERO;
+        return;
+    }
+
+    // Dashing ships move twice as fast as their acceleration says.
+    let dash_factor = if ship_stats.dash { 2.0 } else { 1.0 };
+    let velocity = ship_stats.acceleration * dash_factor;
+    rig.look_ahead = (velocity * settings.look_ahead_time)
+        .clamp_length_max(settings.max_look_ahead * projection.scale);
 }
 
-fn move_camera(
+pub fn move_camera(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut ev_camera_shake: EventReader<CameraShake>,
     mut q_camera: Query<
-        (&mut Transform, &OrthographicProjection),
+        (&mut Transform, &mut CameraRig, &OrthographicProjection),
         (With<MainCamera>, Without<Player>),
     >,
     q_player: Query<&Transform, With<Player>>,
-    mouse_coords: Res<MouseWorldCoords>,
+    aim_coords: Res<AimWorldCoords>,
 ) {
     let player_pos = match q_player.get_single() {
-        Ok(player) => player.translation,
+        Ok(player) => player.translation.truncate(),
         Err(err) => {
             error!("no player! cannot move camera, {}", err);
             return;
         }
     };
-    let (mut camera_transform, projection) = q_camera.single_mut();
+    let (mut camera_transform, mut rig, projection) = q_camera.single_mut();
+    let dt = time.delta_seconds();
+
+    let target = player_pos + (aim_coords.0 - player_pos) / 4.0 / projection.scale + rig.look_ahead;
+    let mut velocity = rig.velocity;
+    rig.position = smooth_damp(
+        rig.position,
+        target,
+        &mut velocity,
+        settings.follow_stiffness,
+        dt,
+    );
+    rig.velocity = velocity;
+
+    for ev in ev_camera_shake.read() {
+        rig.trauma = (rig.trauma + ev.trauma).min(1.0);
+    }
+    rig.trauma = (rig.trauma - settings.trauma_decay * dt).max(0.0);
+
+    let shake = rig.trauma * rig.trauma * settings.shake_intensity;
+    let t = time.elapsed_seconds();
+    let offset = Vec2::new(shake_noise(t, 0.0), shake_noise(t, 10.0))
+        * settings.max_shake_offset
+        * projection.scale
+        * shake;
+    let angle = shake_noise(t, 20.0) * settings.max_shake_angle * shake;
+
+    camera_transform.translation = (rig.position + offset).extend(camera_transform.translation.z);
+    camera_transform.rotation = Quat::from_rotation_z(angle);
+}
+
+fn shake_on_hits(
+    mut ev_projectile_collision: EventReader<ProjectileCollision>,
+    mut ev_camera_shake: EventWriter<CameraShake>,
+    q_player: Query<Entity, With<Player>>,
+) {
+    let player = match q_player.get_single() {
+        Ok(p) => p,
+        Err(_) => return,
+    };
+
+    for ev in ev_projectile_collision.read() {
+        if ev.target == player {
+            ev_camera_shake.send(CameraShake {
+                trauma: (ev.projectile.damage / HIT_TRAUMA_DAMAGE).min(0.5),
+            });
+        }
+    }
+}
+
+fn shake_on_explosions(
+    mut ev_projectile_despawn: EventReader<ProjectileDespawn>,
+    mut ev_camera_shake: EventWriter<CameraShake>,
+    q_player: Query<&Transform, With<Player>>,
+) {
+    let player_pos = match q_player.get_single() {
+        Ok(p) => p.translation.truncate(),
+        Err(_) => return,
+    };
+
+    for ev in ev_projectile_despawn.read() {
+        if !matches!(
+            ev.projectile.projectile_type,
+            ProjectileType::Rocket | ProjectileType::Mine | ProjectileType::Torpedo
+        ) {
+            continue;
+        }
+
+        let distance = ev.position.truncate().distance(player_pos);
+        let falloff = 1.0 - distance / EXPLOSION_SHAKE_RANGE;
+        if falloff > 0.0 {
+            ev_camera_shake.send(CameraShake {
+                trauma: EXPLOSION_TRAUMA * falloff,
+            });
+        }
+    }
+}
+
+fn shake_on_dash(
+    mut was_dashing: Local<bool>,
+    mut ev_camera_shake: EventWriter<CameraShake>,
+    q_player: Query<&ShipStats, With<Player>>,
+) {
+    let dashing = match q_player.get_single() {
+        Ok(s) => s.dash,
+        Err(_) => return,
+    };
 
-    camera_transform.translation =
-        player_pos + (mouse_coords.0.extend(0.0) - player_pos) / 4.0 / projection.scale;
+    if dashing && !*was_dashing {
+        ev_camera_shake.send(CameraShake {
+            trauma: DASH_TRAUMA,
+        });
+    }
+    *was_dashing = dashing;
 }
 
 fn toggle_full_screen(
     mut main_window: Query<&mut Window, With<PrimaryWindow>>,
-    keys: Res<Input<KeyCode>>,
-    gamepads: Res<Gamepads>,
-    button_inputs: Res<Input<GamepadButton>>,
+    actions: Res<ActionInput>,
 ) {
     let mut window = match main_window.get_single_mut() {
         Ok(w) => w,
@@ -67,14 +318,7 @@ fn toggle_full_screen(
         }
     };
 
-    let mut pressed = keys.just_pressed(KeyCode::B);
-    for gamepad in gamepads.iter() {
-        if button_inputs.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::DPadUp)) {
-            pressed = true;
-        }
-    }
-
-    if !pressed {
+    if !actions.just_pressed(Action::ToggleFullscreen) {
         return;
     }
 
@@ -0,0 +1,243 @@
+use bevy::prelude::*;
+
+use crate::player::input::action::{update_actions, Action, ActionInput};
+use crate::projectile::DriftsWithFlow;
+use crate::world::MainCamera;
+use crate::GameState;
+
+const OVERLAY_SPACING: f32 = 200.0;
+/// Length of an overlay arrow per unit of flow.
+const OVERLAY_ARROW_SCALE: f32 = 0.5;
+
+#[derive(Clone, Copy, Debug)]
+pub enum FlowZoneKind {
+    /// Adds its velocity to the flow, strongest at the centre.
+    Current(Vec2),
+    /// Dampens all flow, completely still at the centre.
+    Calm,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FlowZone {
+    pub center: Vec2,
+    pub radius: f32,
+    pub kind: FlowZoneKind,
+}
+
+impl FlowZone {
+    /// 1 at the centre of the zone, fading smoothly to 0 at its edge.
+    fn falloff(&self, position: Vec2) -> f32 {
+        let t = (1.0 - position.distance(self.center) / self.radius).clamp(0.0, 1.0);
+        t * t * (3.0 - 2.0 * t)
+    }
+}
+
+/// Wind and ocean currents. Ships get pushed by the flow,
+/// slow projectiles are carried along with it if enabled.
+#[derive(Resource, Clone)]
+pub struct FlowField {
+    pub wind: Vec2,
+    pub zones: Vec<FlowZone>,
+    /// Multiplier on the whole field, e.g. for storms.
+    pub strength: f32,
+    /// Extra drag on ships from rough seas.
+    pub wave_drag: f32,
+    pub drift_projectiles: bool,
+}
+
+impl Default for FlowField {
+    fn default() -> Self {
+        Self {
+            wind: Vec2::new(30.0, 10.0),
+            zones: vec![
+                FlowZone {
+                    center: Vec2::new(2000.0, 0.0),
+                    radius: 1200.0,
+                    kind: FlowZoneKind::Current(Vec2::new(0.0, 250.0)),
+                },
+                FlowZone {
+                    center: Vec2::new(-2500.0, 1500.0),
+                    radius: 1000.0,
+                    kind: FlowZoneKind::Current(Vec2::new(-200.0, -100.0)),
+                },
+                FlowZone {
+                    center: Vec2::new(0.0, -2500.0),
+                    radius: 900.0,
+                    kind: FlowZoneKind::Calm,
+                },
+            ],
+            strength: 1.0,
+            wave_drag: 0.0,
+            drift_projectiles: true,
+        }
+    }
+}
+
+impl FlowField {
+    pub fn sample(&self, position: Vec2) -> Vec2 {
+        let mut flow = self.wind;
+        let mut calm: f32 = 0.0;
+
+        for zone in &self.zones {
+            let falloff = zone.falloff(position);
+            match zone.kind {
+                FlowZoneKind::Current(velocity) => flow += velocity * falloff,
+                FlowZoneKind::Calm => calm = calm.max(falloff),
+            }
+        }
+        flow * (1.0 - calm) * self.strength
+    }
+}
+
+#[derive(Resource, Default)]
+struct FlowOverlay(bool);
+
+fn drift_projectiles(
+    time: Res<Time>,
+    field: Res<FlowField>,
+    mut q_projectiles: Query<&mut Transform, With<DriftsWithFlow>>,
+) {
+    if !field.drift_projectiles {
+        return;
+    }
+
+    for mut transform in &mut q_projectiles {
+        let flow = field.sample(transform.translation.truncate());
+        transform.translation += flow.extend(0.0) * time.delta_seconds();
+    }
+}
+
+fn toggle_flow_overlay(actions: Res<ActionInput>, mut overlay: ResMut<FlowOverlay>) {
+    if actions.just_pressed(Action::ToggleFlowOverlay) {
+        overlay.0 = !overlay.0;
+    }
+}
+
+fn draw_flow_overlay(
+    mut gizmos: Gizmos,
+    overlay: Res<FlowOverlay>,
+    field: Res<FlowField>,
+    q_camera: Query<(&Transform, &OrthographicProjection), With<MainCamera>>,
+) {
+    if !overlay.0 {
+        return;
+    }
+
+    let (camera_transform, projection) = match q_camera.get_single() {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let spacing = OVERLAY_SPACING * projection.scale;
+    let camera_pos = camera_transform.translation.truncate();
+    let min = ((camera_pos + projection.area.min) / spacing).floor() * spacing;
+    let max = camera_pos + projection.area.max;
+
+    let mut x = min.x;
+    while x <= max.x {
+        let mut y = min.y;
+        while y <= max.y {
+            let start = Vec2::new(x, y);
+            let flow = field.sample(start) * OVERLAY_ARROW_SCALE * projection.scale;
+            let end = start + flow;
+            gizmos.line_2d(start, end, Color::CYAN);
+            if flow != Vec2::ZERO {
+                let head = -flow.normalize() * 10.0 * projection.scale;
+                gizmos.line_2d(end, end + Vec2::from_angle(0.5).rotate(head), Color::CYAN);
+                gizmos.line_2d(end, end + Vec2::from_angle(-0.5).rotate(head), Color::CYAN);
+            }
+            y += spacing;
+        }
+        x += spacing;
+    }
+
+    for zone in &field.zones {
+        let color = match zone.kind {
+            FlowZoneKind::Current(_) => Color::ORANGE,
+            FlowZoneKind::Calm => Color::GREEN,
+        };
+        gizmos.circle_2d(zone.center, zone.radius, color);
+    }
+}
+
+pub struct CurrentsPlugin;
+
+impl Plugin for CurrentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlowField>()
+            .init_resource::<FlowOverlay>()
+            .add_systems(
+                Update,
+                (
+                    drift_projectiles,
+                    toggle_flow_overlay.after(update_actions),
+                    draw_flow_overlay,
+                )
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current(center: Vec2, velocity: Vec2) -> FlowZone {
+        FlowZone {
+            center,
+            radius: 100.0,
+            kind: FlowZoneKind::Current(velocity),
+        }
+    }
+
+    fn field(zones: Vec<FlowZone>) -> FlowField {
+        FlowField {
+            wind: Vec2::ZERO,
+            zones,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn falloff_is_full_at_the_center() {
+        let zone = current(Vec2::new(10.0, 20.0), Vec2::X);
+        assert_eq!(zone.falloff(Vec2::new(10.0, 20.0)), 1.0);
+    }
+
+    #[test]
+    fn falloff_fades_out_at_the_edge() {
+        let zone = current(Vec2::ZERO, Vec2::X);
+        assert_eq!(zone.falloff(Vec2::new(100.0, 0.0)), 0.0);
+        assert_eq!(zone.falloff(Vec2::new(50.0, 0.0)), 0.5);
+    }
+
+    #[test]
+    fn falloff_is_zero_outside() {
+        let zone = current(Vec2::ZERO, Vec2::X);
+        assert_eq!(zone.falloff(Vec2::new(0.0, 250.0)), 0.0);
+    }
+
+    #[test]
+    fn overlapping_currents_add_up() {
+        let field = field(vec![
+            current(Vec2::ZERO, Vec2::new(10.0, 0.0)),
+            current(Vec2::ZERO, Vec2::new(0.0, 5.0)),
+        ]);
+        assert_eq!(field.sample(Vec2::ZERO), Vec2::new(10.0, 5.0));
+    }
+
+    #[test]
+    fn calm_zone_dampens_overlapping_flow() {
+        let mut field = field(vec![
+            current(Vec2::ZERO, Vec2::new(10.0, 0.0)),
+            FlowZone {
+                center: Vec2::ZERO,
+                radius: 100.0,
+                kind: FlowZoneKind::Calm,
+            },
+        ]);
+        field.wind = Vec2::new(0.0, 4.0);
+        assert_eq!(field.sample(Vec2::ZERO), Vec2::ZERO);
+        assert_eq!(field.sample(Vec2::new(0.0, 150.0)), Vec2::new(0.0, 4.0));
+    }
+}
@@ -1,4 +1,13 @@
+pub mod boundary;
 pub mod camera;
+pub mod currents;
+pub mod terrain;
+pub mod water;
+// `ShaderType` derives a `check` function per uniform field that is never called.
+// They are separate items, so an allow on the structs doesn't reach them.
+#[allow(dead_code)]
+pub mod water_material;
+pub mod weather;
 
 pub use camera::MainCamera;
 
@@ -8,6 +17,13 @@ pub struct GuardianWorldPlugin;
 
 impl Plugin for GuardianWorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((camera::GuardianCameraPlugin,));
+        app.add_plugins((
+            boundary::BoundaryPlugin,
+            camera::GuardianCameraPlugin,
+            currents::CurrentsPlugin,
+            terrain::TerrainPlugin,
+            water::WaterPlugin,
+            weather::WeatherPlugin,
+        ));
     }
 }
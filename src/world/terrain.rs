@@ -0,0 +1,249 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use bevy_rapier2d::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::collision::{ENEMY_LAYER, PLAYER_LAYER, PROJECTILE_LAYER, TERRAIN_LAYER};
+use crate::vessel::ship::move_ships;
+use crate::world::camera::move_camera;
+use crate::{GameState, ShipStats};
+
+const TERRAIN_Z: f32 = -50.0;
+const MAX_PLACEMENT_ATTEMPTS: usize = 50;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TerrainKind {
+    Island,
+    Rock,
+    Reef,
+}
+
+impl TerrainKind {
+    fn radius_range(&self) -> Range<f32> {
+        match self {
+            TerrainKind::Island => 150.0..400.0,
+            TerrainKind::Rock => 30.0..80.0,
+            TerrainKind::Reef => 60.0..140.0,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            TerrainKind::Island => Color::rgb(0.85, 0.78, 0.55),
+            TerrainKind::Rock => Color::rgb(0.4, 0.4, 0.42),
+            TerrainKind::Reef => Color::rgba(0.9, 0.5, 0.45, 0.6),
+        }
+    }
+}
+
+/// Settings for the procedural terrain. The same seed always produces the same map.
+#[derive(Resource, Clone)]
+pub struct TerrainConfig {
+    pub seed: u64,
+    /// Terrain is placed inside a square of this half size around the origin.
+    pub half_size: f32,
+    pub islands: usize,
+    pub rocks: usize,
+    pub reefs: usize,
+    /// Minimum distance between the edges of two terrain features.
+    pub spacing: f32,
+    /// Points that have to stay clear of terrain, like vessel spawns.
+    pub spawn_points: Vec<Vec2>,
+    /// Minimum distance between a spawn point and the edge of any terrain.
+    pub spawn_clearance: f32,
+}
+
+impl Default for TerrainConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0x5EA,
+            half_size: 5000.0,
+            islands: 12,
+            rocks: 30,
+            reefs: 15,
+            spacing: 150.0,
+            spawn_points: vec![
+                Vec2::ZERO,
+                Vec2::new(-500.0, -500.0),
+                Vec2::new(500.0, -500.0),
+                Vec2::new(500.0, 500.0),
+                Vec2::new(-500.0, 500.0),
+                Vec2::new(0.0, 1800.0),
+                Vec2::new(0.0, -1800.0),
+                Vec2::new(2500.0, 0.0),
+            ],
+            spawn_clearance: 400.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TerrainFeature {
+    pub kind: TerrainKind,
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct Terrain {
+    pub kind: TerrainKind,
+    pub radius: f32,
+}
+
+/// Place terrain features from the config's seed, larger features first
+/// so the small ones fill the gaps in between.
+pub fn generate_terrain(config: &TerrainConfig) -> Vec<TerrainFeature> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut features: Vec<TerrainFeature> = Vec::new();
+
+    let kinds = [
+        (TerrainKind::Island, config.islands),
+        (TerrainKind::Reef, config.reefs),
+        (TerrainKind::Rock, config.rocks),
+    ];
+    for (kind, count) in kinds {
+        for _ in 0..count {
+            for _ in 0..MAX_PLACEMENT_ATTEMPTS {
+                let radius = rng.gen_range(kind.radius_range());
+                let position = Vec2::new(
+                    rng.gen_range(-config.half_size..config.half_size),
+                    rng.gen_range(-config.half_size..config.half_size),
+                );
+
+                let blocks_spawn = config
+                    .spawn_points
+                    .iter()
+                    .any(|p| p.distance(position) < radius + config.spawn_clearance);
+                let overlaps = features
+                    .iter()
+                    .any(|f| f.position.distance(position) < f.radius + radius + config.spacing);
+                if blocks_spawn || overlaps {
+                    continue;
+                }
+
+                features.push(TerrainFeature {
+                    kind,
+                    position,
+                    radius,
+                });
+                break;
+            }
+        }
+    }
+    features
+}
+
+fn spawn_terrain(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<TerrainConfig>,
+) {
+    for feature in generate_terrain(&config) {
+        let entity = commands
+            .spawn((
+                Terrain {
+                    kind: feature.kind,
+                    radius: feature.radius,
+                },
+                MaterialMesh2dBundle {
+                    mesh: meshes.add(shape::Circle::new(feature.radius).into()).into(),
+                    material: materials.add(ColorMaterial::from(feature.kind.color())),
+                    transform: Transform::from_translation(feature.position.extend(TERRAIN_Z)),
+                    ..default()
+                },
+                Collider::ball(feature.radius),
+                CollisionGroups::new(
+                    Group::from_bits(TERRAIN_LAYER).unwrap(),
+                    Group::from_bits(PROJECTILE_LAYER | PLAYER_LAYER | ENEMY_LAYER).unwrap(),
+                ),
+            ))
+            .id();
+
+        if feature.kind == TerrainKind::Island {
+            let vegetation = commands
+                .spawn(MaterialMesh2dBundle {
+                    mesh: meshes
+                        .add(shape::Circle::new(feature.radius * 0.75).into())
+                        .into(),
+                    material: materials.add(ColorMaterial::from(Color::rgb(0.3, 0.55, 0.25))),
+                    transform: Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+                    ..default()
+                })
+                .id();
+            commands.entity(entity).push_children(&[vegetation]);
+        }
+    }
+}
+
+/// Push ships out of any terrain they sailed into
+/// and remove the part of their velocity pointing into it.
+fn block_ships(
+    rapier_context: Res<RapierContext>,
+    mut q_ships: Query<(&mut Transform, &mut ShipStats, &Collider, &CollisionGroups)>,
+    q_terrain: Query<(&Transform, &Terrain), Without<ShipStats>>,
+) {
+    for (mut transform, mut ship_stats, collider, groups) in &mut q_ships {
+        let filter = QueryFilter {
+            groups: Some(CollisionGroups::new(
+                groups.memberships,
+                Group::from_bits(TERRAIN_LAYER).unwrap(),
+            )),
+            ..default()
+        };
+
+        let mut hits = Vec::new();
+        rapier_context.intersections_with_shape(
+            transform.translation.truncate(),
+            transform.rotation.to_euler(EulerRot::ZYX).0,
+            collider,
+            filter,
+            |other| {
+                hits.push(other);
+                true
+            },
+        );
+
+        for other in hits {
+            let (terrain_transform, terrain) = match q_terrain.get(other) {
+                Ok(t) => t,
+                Err(_) => continue,
+            };
+
+            let center = terrain_transform.translation.truncate();
+            let distance = collider.distance_to_point(
+                transform.translation.truncate(),
+                transform.rotation.to_euler(EulerRot::ZYX).0,
+                center,
+                true,
+            );
+            let penetration = terrain.radius - distance;
+            if penetration <= 0.0 {
+                continue;
+            }
+
+            let normal = (transform.translation.truncate() - center).normalize_or_zero();
+            transform.translation += (normal * penetration).extend(0.0);
+
+            let into_terrain = ship_stats.acceleration.dot(normal).min(0.0);
+            ship_stats.acceleration -= normal * into_terrain;
+        }
+    }
+}
+
+pub struct TerrainPlugin;
+
+impl Plugin for TerrainPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TerrainConfig>()
+            .add_systems(OnEnter(GameState::Gaming), spawn_terrain)
+            .add_systems(
+                Update,
+                (block_ships.after(move_ships).before(move_camera),)
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use bevy::sprite::{Material2dPlugin, MaterialMesh2dBundle, Mesh2dHandle};
+use bevy::utils::HashSet;
+
+use crate::world::camera::move_camera;
+use crate::world::water_material::{WaterMaterial, WaterParams};
+use crate::world::MainCamera;
+use crate::GameState;
+
+const CHUNK_SIZE: f32 = 6400.0;
+const WATER_Z: f32 = -100.0;
+
+#[derive(Resource)]
+struct WaterHandles {
+    mesh: Mesh2dHandle,
+    material: Handle<WaterMaterial>,
+}
+
+/// A single tile of the ocean background. Chunks that leave the view
+/// are hidden and moved to the next chunk coordinate that needs one.
+#[derive(Component)]
+pub struct WaterChunk {
+    pub coords: IVec2,
+}
+
+fn chunk_coords(position: Vec2) -> IVec2 {
+    (position / CHUNK_SIZE).round().as_ivec2()
+}
+
+fn chunk_translation(coords: IVec2) -> Vec3 {
+    (coords.as_vec2() * CHUNK_SIZE).extend(WATER_Z)
+}
+
+fn setup_water(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<WaterMaterial>>,
+    params: Res<WaterParams>,
+) {
+    commands.insert_resource(WaterHandles {
+        mesh: meshes
+            .add(shape::Quad::new(Vec2::splat(CHUNK_SIZE)).into())
+            .into(),
+        material: materials.add(params.material()),
+    });
+}
+
+fn sync_water_material(
+    params: Res<WaterParams>,
+    handles: Res<WaterHandles>,
+    mut materials: ResMut<Assets<WaterMaterial>>,
+) {
+    if !params.is_changed() {
+        return;
+    }
+
+    if let Some(material) = materials.get_mut(&handles.material) {
+        *material = params.material();
+    }
+}
+
+fn spawn_water_chunk(commands: &mut Commands, handles: &WaterHandles, coords: IVec2) {
+    commands.spawn((
+        WaterChunk { coords },
+        MaterialMesh2dBundle {
+            mesh: handles.mesh.clone(),
+            material: handles.material.clone(),
+            transform: Transform::from_translation(chunk_translation(coords)),
+            ..default()
+        },
+    ));
+}
+
+fn update_water_chunks(
+    mut commands: Commands,
+    handles: Res<WaterHandles>,
+    q_camera: Query<(&Transform, &OrthographicProjection), With<MainCamera>>,
+    mut q_chunks: Query<(&mut WaterChunk, &mut Transform, &mut Visibility), Without<MainCamera>>,
+) {
+    let (camera_transform, projection) = match q_camera.get_single() {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let camera_pos = camera_transform.translation.truncate();
+    let min = chunk_coords(camera_pos + projection.area.min);
+    let max = chunk_coords(camera_pos + projection.area.max);
+
+    let mut missing = HashSet::new();
+    for x in min.x..=max.x {
+        for y in min.y..=max.y {
+            missing.insert(IVec2::new(x, y));
+        }
+    }
+
+    let mut free_chunks = Vec::new();
+    for (chunk, transform, visibility) in &mut q_chunks {
+        if *visibility != Visibility::Hidden && missing.remove(&chunk.coords) {
+            continue;
+        }
+        free_chunks.push((chunk, transform, visibility));
+    }
+
+    for coords in missing {
+        match free_chunks.pop() {
+            Some((mut chunk, mut transform, mut visibility)) => {
+                chunk.coords = coords;
+                transform.translation = chunk_translation(coords);
+                *visibility = Visibility::Inherited;
+            }
+            None => spawn_water_chunk(&mut commands, &handles, coords),
+        }
+    }
+
+    for (_, _, mut visibility) in free_chunks {
+        if *visibility != Visibility::Hidden {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+pub struct WaterPlugin;
+
+impl Plugin for WaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<WaterMaterial>::default())
+            .init_resource::<WaterParams>()
+            .add_systems(Startup, setup_water)
+            .add_systems(
+                Update,
+                (update_water_chunks.after(move_camera), sync_water_material)
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
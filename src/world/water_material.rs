@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+use bevy::sprite::Material2d;
+
+#[derive(ShaderType, Clone, Copy, Debug)]
+struct WaterShaderParams {
+    scale: f32,
+    height: f32,
+    tide: f32,
+    foam_thickness: f32,
+    time_scale: f32,
+    water_depth: f32,
+    time_offset: f32,
+}
+
+#[derive(ShaderType, Clone, Copy, Debug)]
+struct WaterShaderColors {
+    w1: Vec4,
+    w2: Vec4,
+    foam: Vec4,
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct WaterMaterial {
+    #[uniform(0)]
+    params: WaterShaderParams,
+    #[uniform(1)]
+    colors: WaterShaderColors,
+}
+
+impl Material2d for WaterMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "water_shader.wgsl".into()
+    }
+}
+
+/// Runtime parameters of the water shader. Systems like weather or tides
+/// change these and the material is updated to match.
+#[derive(Resource, Clone)]
+pub struct WaterParams {
+    /// Frequency of the wave noise.
+    pub scale: f32,
+    /// Noise level at which foam starts, higher means less foam.
+    pub height: f32,
+    /// How much the foam level moves with the waves.
+    pub tide: f32,
+    pub foam_thickness: f32,
+    /// Speed of the animation, change it through `set_time_scale`.
+    pub time_scale: f32,
+    pub water_depth: f32,
+    /// Added to the scaled shader time so the waves continue where they were
+    /// when `time_scale` changes.
+    pub time_offset: f32,
+    pub water_color: Color,
+    pub shadow_color: Color,
+    pub foam_color: Color,
+}
+
+impl Default for WaterParams {
+    fn default() -> Self {
+        Self {
+            scale: 4.0,
+            height: 0.6,
+            tide: 0.1,
+            foam_thickness: 0.03,
+            time_scale: 1.0,
+            water_depth: 1.0,
+            time_offset: 0.0,
+            water_color: Color::rgb(0.11, 0.3, 0.5),
+            shadow_color: Color::rgb(0.08, 0.22, 0.4),
+            foam_color: Color::rgb(0.8, 0.9, 0.95),
+        }
+    }
+}
+
+impl WaterParams {
+    /// Change the animation speed without jumping the waves, `elapsed` has to be
+    /// the wrapped time the shader sees as `globals.time`.
+    pub fn set_time_scale(&mut self, time_scale: f32, elapsed: f32) {
+        self.time_offset += elapsed * (self.time_scale - time_scale);
+        self.time_scale = time_scale;
+    }
+
+    pub fn material(&self) -> WaterMaterial {
+        WaterMaterial {
+            params: WaterShaderParams {
+                scale: self.scale,
+                height: self.height,
+                tide: self.tide,
+                foam_thickness: self.foam_thickness,
+                time_scale: self.time_scale,
+                water_depth: self.water_depth,
+                time_offset: self.time_offset,
+            },
+            colors: WaterShaderColors {
+                w1: self.water_color.as_linear_rgba_f32().into(),
+                w2: self.shadow_color.as_linear_rgba_f32().into(),
+                foam: self.foam_color.as_linear_rgba_f32().into(),
+            },
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::world::currents::FlowField;
+use crate::world::water_material::WaterParams;
+use crate::GameState;
+
+/// How fast the blended weather moves towards the current weather, per second.
+const BLEND_RATE: f32 = 0.2;
+const NIGHT_COLOR: Color = Color::rgb(0.02, 0.03, 0.12);
+const FOG_COLOR: Color = Color::rgb(0.6, 0.65, 0.7);
+const MAX_NIGHT_ALPHA: f32 = 0.55;
+const MAX_FOG_ALPHA: f32 = 0.5;
+/// Detection range factor at midnight, scaled up to 1 at noon.
+const NIGHT_DETECTION: f32 = 0.5;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    Fog,
+    Storm,
+}
+
+impl Weather {
+    fn params(&self) -> WeatherParams {
+        match self {
+            Weather::Clear => WeatherParams::default(),
+            Weather::Rain => WeatherParams {
+                detection: 0.85,
+                flow_strength: 1.3,
+                wave_drag: 0.1,
+                fog: 0.2,
+                water_height: 0.55,
+                water_tide: 0.15,
+                water_time_scale: 1.3,
+                ..default()
+            },
+            Weather::Fog => WeatherParams {
+                turret_range: 0.5,
+                detection: 0.6,
+                flow_strength: 0.7,
+                fog: 1.0,
+                water_height: 0.62,
+                water_tide: 0.05,
+                water_time_scale: 0.7,
+                ..default()
+            },
+            Weather::Storm => WeatherParams {
+                turret_range: 0.8,
+                detection: 0.8,
+                flow_strength: 2.5,
+                wave_drag: 0.5,
+                fog: 0.4,
+                water_height: 0.45,
+                water_tide: 0.3,
+                water_time_scale: 2.5,
+            },
+        }
+    }
+
+    fn next(&self) -> Weather {
+        let options = [Weather::Clear, Weather::Rain, Weather::Fog, Weather::Storm];
+        let mut rng = rand::thread_rng();
+        loop {
+            let weather = options[rng.gen_range(0..options.len())];
+            if weather != *self {
+                return weather;
+            }
+        }
+    }
+}
+
+/// The values weather changes. These get blended over time
+/// so the switch from one weather to the next is gradual.
+#[derive(Clone, Copy)]
+struct WeatherParams {
+    turret_range: f32,
+    detection: f32,
+    flow_strength: f32,
+    wave_drag: f32,
+    fog: f32,
+    water_height: f32,
+    water_tide: f32,
+    water_time_scale: f32,
+}
+
+impl Default for WeatherParams {
+    fn default() -> Self {
+        Self {
+            turret_range: 1.0,
+            detection: 1.0,
+            flow_strength: 1.0,
+            wave_drag: 0.0,
+            fog: 0.0,
+            water_height: 0.6,
+            water_tide: 0.1,
+            water_time_scale: 1.0,
+        }
+    }
+}
+
+impl WeatherParams {
+    fn lerp(&self, other: &WeatherParams, t: f32) -> WeatherParams {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        WeatherParams {
+            turret_range: lerp(self.turret_range, other.turret_range),
+            detection: lerp(self.detection, other.detection),
+            flow_strength: lerp(self.flow_strength, other.flow_strength),
+            wave_drag: lerp(self.wave_drag, other.wave_drag),
+            fog: lerp(self.fog, other.fog),
+            water_height: lerp(self.water_height, other.water_height),
+            water_tide: lerp(self.water_tide, other.water_tide),
+            water_time_scale: lerp(self.water_time_scale, other.water_time_scale),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct WeatherState {
+    pub current: Weather,
+    /// Time until the weather changes again.
+    pub timer: Timer,
+    blended: WeatherParams,
+}
+
+impl Default for WeatherState {
+    fn default() -> Self {
+        Self {
+            current: Weather::default(),
+            timer: Timer::from_seconds(90.0, TimerMode::Once),
+            blended: WeatherParams::default(),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct DayNight {
+    /// 0 is midnight, 0.5 is noon.
+    pub time_of_day: f32,
+    /// Length of a full day in seconds.
+    pub day_length: f32,
+}
+
+impl Default for DayNight {
+    fn default() -> Self {
+        Self {
+            time_of_day: 0.35,
+            day_length: 600.0,
+        }
+    }
+}
+
+impl DayNight {
+    /// 0 at midnight, 1 at noon.
+    pub fn daylight(&self) -> f32 {
+        0.5 - 0.5 * (self.time_of_day * TAU).cos()
+    }
+}
+
+/// Gameplay modifiers from weather and time of day, read by turrets and AI.
+#[derive(Resource, Clone, Copy)]
+pub struct WeatherEffects {
+    pub turret_range_factor: f32,
+    pub detection_factor: f32,
+}
+
+impl Default for WeatherEffects {
+    fn default() -> Self {
+        Self {
+            turret_range_factor: 1.0,
+            detection_factor: 1.0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct AmbientTint;
+
+fn spawn_ambient_tint(mut commands: Commands) {
+    commands.spawn((
+        AmbientTint,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            // Below every other UI node so the HUD stays readable.
+            z_index: ZIndex::Global(-1),
+            ..default()
+        },
+    ));
+}
+
+fn advance_day_night(time: Res<Time>, mut day_night: ResMut<DayNight>) {
+    let delta = time.delta_seconds() / day_night.day_length;
+    day_night.time_of_day = (day_night.time_of_day + delta).fract();
+}
+
+fn cycle_weather(time: Res<Time>, mut weather: ResMut<WeatherState>) {
+    weather.timer.tick(time.delta());
+    if !weather.timer.just_finished() {
+        return;
+    }
+
+    weather.current = weather.current.next();
+    let duration = rand::thread_rng().gen_range(60.0..120.0);
+    weather.timer = Timer::from_seconds(duration, TimerMode::Once);
+    info!("weather changed to {:?}", weather.current);
+}
+
+fn blend_weather(time: Res<Time>, mut weather: ResMut<WeatherState>) {
+    let target = weather.current.params();
+    let t = (BLEND_RATE * time.delta_seconds()).min(1.0);
+    weather.blended = weather.blended.lerp(&target, t);
+}
+
+fn apply_weather(
+    time: Res<Time>,
+    weather: Res<WeatherState>,
+    day_night: Res<DayNight>,
+    mut effects: ResMut<WeatherEffects>,
+    mut flow_field: ResMut<FlowField>,
+    mut water_params: ResMut<WaterParams>,
+) {
+    let params = weather.blended;
+    let darkness = 1.0 - day_night.daylight();
+
+    effects.turret_range_factor = params.turret_range;
+    effects.detection_factor = params.detection * (1.0 - darkness * (1.0 - NIGHT_DETECTION));
+
+    flow_field.strength = params.flow_strength;
+    flow_field.wave_drag = params.wave_drag;
+
+    // Only touch the water once the values move, every change rebuilds the material.
+    if water_params.height != params.water_height
+        || water_params.tide != params.water_tide
+        || water_params.time_scale != params.water_time_scale
+    {
+        water_params.height = params.water_height;
+        water_params.tide = params.water_tide;
+        water_params.set_time_scale(params.water_time_scale, time.elapsed_seconds_wrapped());
+    }
+}
+
+fn tint_ambient(
+    weather: Res<WeatherState>,
+    day_night: Res<DayNight>,
+    mut q_tint: Query<&mut BackgroundColor, With<AmbientTint>>,
+) {
+    let night_alpha = (1.0 - day_night.daylight()) * MAX_NIGHT_ALPHA;
+    let fog_alpha = weather.blended.fog * MAX_FOG_ALPHA;
+    let alpha = 1.0 - (1.0 - night_alpha) * (1.0 - fog_alpha);
+
+    let color = if alpha > 0.0 {
+        let night = Vec3::from_slice(&NIGHT_COLOR.as_rgba_f32()[..3]) * night_alpha;
+        let fog = Vec3::from_slice(&FOG_COLOR.as_rgba_f32()[..3]) * fog_alpha;
+        let rgb = (night + fog) / (night_alpha + fog_alpha);
+        Color::rgba(rgb.x, rgb.y, rgb.z, alpha)
+    } else {
+        Color::NONE
+    };
+
+    for mut background in &mut q_tint {
+        background.0 = color;
+    }
+}
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeatherState>()
+            .init_resource::<DayNight>()
+            .init_resource::<WeatherEffects>()
+            .add_systems(OnEnter(GameState::Gaming), spawn_ambient_tint)
+            .add_systems(
+                Update,
+                (
+                    advance_day_night,
+                    cycle_weather,
+                    blend_weather,
+                    apply_weather,
+                    tint_ambient,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}